@@ -0,0 +1,143 @@
+//! A global allocator that counts allocations, for profiling each phase of a day.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use advent2025_lib::bench::{Phase, Probe};
+
+/// The system allocator, counting what passes through it.
+struct Counting {
+    allocations: AtomicU64,
+    bytes: AtomicU64,
+    live: AtomicU64,
+    peak: AtomicU64,
+}
+
+impl Counting {
+    fn record(&self, size: usize) {
+        self.allocations.fetch_add(1, Ordering::Relaxed);
+        self.bytes.fetch_add(size as u64, Ordering::Relaxed);
+        let live = self.live.fetch_add(size as u64, Ordering::Relaxed) + size as u64;
+        self.peak.fetch_max(live, Ordering::Relaxed);
+    }
+
+    fn release(&self, size: usize) {
+        self.live.fetch_sub(size as u64, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            self.record(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            self.record(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        self.release(layout.size());
+    }
+
+    /// Counted as a new allocation of `new_size`, replacing the old one.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            self.release(layout.size());
+            self.record(new_size);
+        }
+        new_ptr
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting {
+    allocations: AtomicU64::new(0),
+    bytes: AtomicU64::new(0),
+    live: AtomicU64::new(0),
+    peak: AtomicU64::new(0),
+};
+
+/// What one phase allocated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    pub allocations: u64,
+    pub bytes: u64,
+    /// The most bytes live at once during the phase, beyond those live when it started.
+    pub peak: u64,
+}
+
+/// Records the allocations of parsing and each part.
+#[derive(Debug, Default)]
+pub struct AllocProbe {
+    allocations: u64,
+    bytes: u64,
+    live: u64,
+    pub parse: AllocStats,
+    pub part1: AllocStats,
+    pub part2: AllocStats,
+}
+
+impl AllocProbe {
+    /// Combines a probe of part 1 on its input with one of part 2 on its own, keeping the part
+    /// each was probed for and both parses.
+    pub fn merge_parts(first: AllocProbe, second: AllocProbe) -> AllocProbe {
+        AllocProbe {
+            parse: AllocStats {
+                allocations: first.parse.allocations + second.parse.allocations,
+                bytes: first.parse.bytes + second.parse.bytes,
+                peak: first.parse.peak.max(second.parse.peak),
+            },
+            part1: first.part1,
+            ..second
+        }
+    }
+}
+
+impl Probe for AllocProbe {
+    fn start(&mut self, _phase: Phase) {
+        self.allocations = ALLOCATOR.allocations.load(Ordering::Relaxed);
+        self.bytes = ALLOCATOR.bytes.load(Ordering::Relaxed);
+        self.live = ALLOCATOR.live.load(Ordering::Relaxed);
+        ALLOCATOR.peak.store(self.live, Ordering::Relaxed);
+    }
+
+    fn stop(&mut self, phase: Phase) {
+        let stats = AllocStats {
+            allocations: ALLOCATOR.allocations.load(Ordering::Relaxed) - self.allocations,
+            bytes: ALLOCATOR.bytes.load(Ordering::Relaxed) - self.bytes,
+            peak: ALLOCATOR.peak.load(Ordering::Relaxed) - self.live,
+        };
+        match phase {
+            Phase::Parse => self.parse = stats,
+            Phase::Part1 => self.part1 = stats,
+            Phase::Part2 => self.part2 = stats,
+            Phase::Total => {}
+        }
+    }
+}
+
+/// Formats `bytes` in the largest binary unit that keeps it at least 1.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
@@ -0,0 +1,217 @@
+//! Logging, filtered by `RUST_LOG` as usual except for days given their own level with `--log`,
+//! and with each day's lines optionally written to its own file.
+//!
+//! Lines logged while a day is parsed or solved are tagged with the day and part.
+
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::fs::{self, File};
+use std::io::{LineWriter, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Mutex;
+
+use advent2025_lib::logging::{self, Scope};
+use log::{LevelFilter, Log, Metadata, Record};
+
+/// The most verbose level to log for one day, whatever `RUST_LOG` says.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DayFilter {
+    /// The year of the day, or `None` for the day of that number in every year.
+    pub year: Option<usize>,
+    pub day: usize,
+    pub level: LevelFilter,
+}
+
+impl DayFilter {
+    fn matches(&self, scope: Scope) -> bool {
+        self.year.is_none_or(|year| year == scope.year) && self.day == scope.day
+    }
+}
+
+impl FromStr for DayFilter {
+    type Err = String;
+
+    /// Parses `[YEAR:]DAY:LEVEL`, such as `8:debug` or `2025:8:debug`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (puzzle, level) = s
+            .rsplit_once(':')
+            .ok_or_else(|| format!("expected [YEAR:]DAY:LEVEL, found `{s}`"))?;
+        let (year, day) = match puzzle.split_once(':') {
+            Some((year, day)) => (
+                Some(
+                    year.parse()
+                        .map_err(|_| format!("expected a year, found `{year}`"))?,
+                ),
+                day,
+            ),
+            None => (None, puzzle),
+        };
+        Ok(DayFilter {
+            year,
+            day: day
+                .parse()
+                .map_err(|_| format!("expected a day number, found `{day}`"))?,
+            level: level.parse().map_err(|_| {
+                format!("unknown level `{level}`, expected off, error, warn, info, debug or trace")
+            })?,
+        })
+    }
+}
+
+pub struct DayLogger {
+    /// The filter of `RUST_LOG`, for lines outside any day and days without a [`DayFilter`].
+    env: env_logger::Logger,
+    /// Writes to stderr whatever it is given, once filtered.
+    stderr: env_logger::Logger,
+    filters: Vec<DayFilter>,
+    /// Where each day's log file goes, as `YYYY/dayNN.log`, instead of stderr.
+    dir: Option<PathBuf>,
+    files: Mutex<HashMap<(usize, usize), LineWriter<File>>>,
+}
+
+impl DayLogger {
+    /// Installs the logger for the whole run.
+    pub fn install(
+        filters: Vec<DayFilter>,
+        dir: Option<PathBuf>,
+    ) -> Result<(), log::SetLoggerError> {
+        let env = env_logger::Logger::from_default_env();
+        let stderr = env_logger::Builder::new()
+            .filter_level(LevelFilter::Trace)
+            .format(|buf, record| {
+                let style = buf.default_level_style(record.level());
+                write!(
+                    buf,
+                    "[{} {style}{:<5}{style:#} {}] ",
+                    buf.timestamp(),
+                    record.level(),
+                    record.target()
+                )?;
+                if let Some(scope) = logging::current() {
+                    write!(buf, "[{scope}] ")?;
+                }
+                writeln!(buf, "{}", record.args())
+            })
+            .build();
+        let max_level = filters
+            .iter()
+            .map(|filter| filter.level)
+            .fold(env.filter(), Ord::max);
+        log::set_boxed_logger(Box::new(DayLogger {
+            env,
+            stderr,
+            filters,
+            dir,
+            files: Mutex::new(HashMap::new()),
+        }))?;
+        log::set_max_level(max_level);
+        Ok(())
+    }
+
+    /// The level given to the day of `scope` with `--log`, the last one given winning.
+    fn day_level(&self, scope: Option<Scope>) -> Option<LevelFilter> {
+        let scope = scope?;
+        self.filters
+            .iter()
+            .rev()
+            .find(|filter| filter.matches(scope))
+            .map(|filter| filter.level)
+    }
+
+    /// Appends the line to the day's log file, opening it on its first line of the run.
+    fn write_file(&self, dir: &Path, scope: Scope, record: &Record) -> std::io::Result<()> {
+        let mut files = self.files.lock().unwrap();
+        let file = match files.entry((scope.year, scope.day)) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let dir = dir.join(scope.year.to_string());
+                fs::create_dir_all(&dir)?;
+                let path = dir.join(format!("day{:02}.log", scope.day));
+                entry.insert(LineWriter::new(File::create(path)?))
+            }
+        };
+        writeln!(
+            file,
+            "[{:<5} {}] [{scope}] {}",
+            record.level(),
+            record.target(),
+            record.args()
+        )
+    }
+}
+
+impl Log for DayLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        match self.day_level(logging::current()) {
+            Some(level) => metadata.level() <= level,
+            None => self.env.enabled(metadata),
+        }
+    }
+
+    fn log(&self, record: &Record) {
+        let scope = logging::current();
+        let enabled = match self.day_level(scope) {
+            Some(level) => record.level() <= level,
+            None => self.env.matches(record),
+        };
+        if !enabled {
+            return;
+        }
+        if let (Some(dir), Some(scope)) = (&self.dir, scope) {
+            if let Err(e) = self.write_file(dir, scope, record) {
+                eprintln!("cannot write the log of {scope}: {e}");
+            }
+            return;
+        }
+        self.stderr.log(record);
+    }
+
+    fn flush(&self) {
+        for file in self.files.lock().unwrap().values_mut() {
+            let _ = file.flush();
+        }
+        self.stderr.flush();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use super::*;
+    use advent2025_lib::bench::Phase;
+
+    #[test]
+    fn test_day_filter_year() {
+        let filter = |s: &str| s.parse::<DayFilter>();
+        assert_eq!(
+            filter("8:debug"),
+            Ok(DayFilter {
+                year: None,
+                day: 8,
+                level: LevelFilter::Debug,
+            })
+        );
+        assert_eq!(
+            filter("2024:8:trace"),
+            Ok(DayFilter {
+                year: Some(2024),
+                day: 8,
+                level: LevelFilter::Trace,
+            })
+        );
+        assert!(filter("x:8:debug").is_err());
+        assert!(filter("2024:8").is_err());
+
+        let scope = |year, day| Scope {
+            year,
+            day,
+            phase: Phase::Parse,
+        };
+        let filter = filter("2024:8:trace").unwrap();
+        assert!(filter.matches(scope(2024, 8)));
+        assert!(!filter.matches(scope(2025, 8)));
+        assert!(!filter.matches(scope(2024, 9)));
+    }
+}
@@ -1,19 +1,80 @@
-use advent2025_lib::{DayTrait, Part, PrimaryExample, get_days};
-use clap::{Arg, Command};
-use color_eyre::Report;
+#[cfg(feature = "alloc-profile")]
+mod alloc;
+mod logging;
+mod output;
+mod progress;
+mod repl;
+mod validate;
+mod visualize;
+mod watch;
+
+use std::collections::{BTreeMap, HashMap};
+use std::io::{ErrorKind, Write};
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use advent2025_lib::answers::{self, Answers, Verdict};
+use advent2025_lib::baseline::{BASELINE_DIR, Baseline, Change, Comparison};
+use advent2025_lib::bench::{
+    BenchConfig, BenchReport, DayBench, Phase, PhaseTimes, bench_day, phase_stats, timed,
+};
+use advent2025_lib::input::{INPUT_DIR_VAR, NamedInput, PathInput, StdinInput};
+use advent2025_lib::panic::isolated;
+use advent2025_lib::progress::CancelToken;
+use advent2025_lib::scaffold::{Flavour, Scaffold};
+use advent2025_lib::scheduler::{self, Event, InOrder, Job};
+use advent2025_lib::visualize::Frame;
+use advent2025_lib::{
+    AnswerResult, ChainInput, Context, DEFAULT_VARIANT, DayTrait, Example, InputSource,
+    ParamOverrides, ParseResult, Part, PrimaryExample, VariantAnswers, Year, get_years,
+};
+use clap::{Arg, ArgMatches, Command};
+use color_eyre::{Report, eyre::eyre};
 use colored::*;
+use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
+use logging::{DayFilter, DayLogger};
+use output::{DayRecord, Format, Printer, Solved, print_year};
+use progress::Bar;
+use visualize::{ImageFormat, Recorder};
 
 fn build_cli() -> Command {
     Command::new("advent2025")
+        .arg(
+            Arg::new("year")
+                .value_parser(clap::value_parser!(usize))
+                .help("The year to solve, or alone, the day to solve if no year has that number [default: the latest]")
+                .required(false),
+        )
         .arg(
             Arg::new("puzzle")
                 .value_parser(clap::value_parser!(usize))
+                .help("The day to solve [default: the latest]")
                 .required(false),
         )
         .arg(Arg::new("all").long("all").action(clap::ArgAction::SetTrue))
         .arg(
             Arg::new("parallel")
                 .long("parallel")
+                .help("Run every day on a pool of worker threads")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("jobs")
+                .long("jobs")
+                .short('j')
+                .value_name("N")
+                .help("Worker threads for --parallel [default: one per CPU]")
+                .value_parser(clap::builder::RangedU64ValueParser::<usize>::new().range(1..))
+                .requires("parallel"),
+        )
+        .arg(
+            Arg::new("split-parts")
+                .long("split-parts")
+                .help("Solve part 1 and part 2 of a day as separate jobs, each parsing the input")
+                .requires("parallel")
                 .action(clap::ArgAction::SetTrue),
         )
         .arg(
@@ -21,91 +82,1254 @@ fn build_cli() -> Command {
                 .long("example")
                 .action(clap::ArgAction::SetTrue),
         )
+        .arg(
+            Arg::new("input")
+                .long("input")
+                .value_name("PATH|-")
+                .help("Read the puzzle input from a file, or from stdin with `-`")
+                .conflicts_with_all(["all", "parallel", "example"]),
+        )
+        .arg(
+            Arg::new("input-name")
+                .long("input-name")
+                .value_name("NAME")
+                .help("Read the alternate input dayNN-NAME.txt instead")
+                .conflicts_with_all(["input", "example"]),
+        )
+        .arg(
+            Arg::new("verify")
+                .long("verify")
+                .help("Check the answers against the expected answers file")
+                .conflicts_with_all(["example", "compare-variants"])
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("answers")
+                .long("answers")
+                .value_name("PATH")
+                .help("Expected answers file for --verify [default: answers.toml]")
+                .requires("verify"),
+        )
+        .arg(
+            Arg::new("param")
+                .long("param")
+                .value_name("KEY=VALUE")
+                .help("Override a puzzle parameter, such as `pairs=500` for day 8")
+                .value_parser(parse_param)
+                .conflicts_with_all(["all", "parallel", "verify"])
+                .action(clap::ArgAction::Append),
+        )
+        .arg(
+            Arg::new("variant")
+                .long("variant")
+                .help("Solve with the named alternative solver, where the day has one"),
+        )
+        .arg(
+            Arg::new("compare-variants")
+                .long("compare-variants")
+                .help("Solve with every solver variant and flag any disagreement")
+                .conflicts_with("variant")
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("bench")
+                .long("bench")
+                .help("Time parsing and each part over many iterations")
+                .conflicts_with_all(["all", "parallel", "verify", "compare-variants", "variant"])
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("warmup")
+                .long("warmup")
+                .value_name("N")
+                .help(format!(
+                    "Untimed runs of each day before timing it [default: {}]",
+                    BenchConfig::default().warmup
+                ))
+                .value_parser(clap::value_parser!(usize))
+                .requires("bench"),
+        )
+        .arg(
+            Arg::new("iterations")
+                .long("iterations")
+                .value_name("N")
+                .help(format!(
+                    "Timed runs of each day [default: {}]",
+                    BenchConfig::default().iterations
+                ))
+                .value_parser(clap::builder::RangedU64ValueParser::<usize>::new().range(1..))
+                .requires("bench"),
+        )
+        .arg(
+            Arg::new("bench-json")
+                .long("bench-json")
+                .value_name("PATH")
+                .help("Also write the benchmark results as JSON")
+                .requires("bench"),
+        )
+        .arg(
+            Arg::new("save-baseline")
+                .long("save-baseline")
+                .value_name("NAME")
+                .help(format!(
+                    "Save the benchmark results as a named baseline in {BASELINE_DIR}"
+                ))
+                .requires("bench"),
+        )
+        .arg(
+            Arg::new("baseline")
+                .long("baseline")
+                .value_name("NAME")
+                .help("Compare the benchmark results against a saved baseline")
+                .requires("bench"),
+        )
+        .arg(
+            Arg::new("threshold")
+                .long("threshold")
+                .value_name("PERCENT")
+                .help("Fail when a significant regression is slower than the baseline by more than this [default: 5]")
+                .value_parser(clap::value_parser!(f64))
+                .requires("baseline"),
+        )
+        .arg(
+            Arg::new("timeout")
+                .long("timeout")
+                .value_name("DURATION")
+                .help("Stop solving a day, or a part with --split-parts, after this long, such as `10s` or `500ms`")
+                .value_parser(parse_duration)
+                .conflicts_with_all(["verify", "compare-variants", "bench", "alloc"]),
+        )
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .help("How to print the answers")
+                .value_parser(clap::builder::PossibleValuesParser::new(Format::NAMES))
+                .default_value("text")
+                .conflicts_with_all(["verify", "compare-variants", "bench", "alloc"]),
+        )
+        .arg(
+            Arg::new("visualize")
+                .long("visualize")
+                .help("Record the frames the day emits of its intermediate states, then play them")
+                .conflicts_with_all(["all", "parallel", "verify", "compare-variants", "bench", "alloc"])
+                .action(clap::ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("fps")
+                .long("fps")
+                .value_name("N")
+                .help("Frames per second to play or record the frames at [default: 10]")
+                .value_parser(clap::value_parser!(f64))
+                .requires("visualize"),
+        )
+        .arg(
+            Arg::new("cast")
+                .long("cast")
+                .value_name("PATH")
+                .help("Save the frames as an asciicast v2 recording instead of playing them")
+                .requires("visualize"),
+        )
+        .arg(
+            Arg::new("frames")
+                .long("frames")
+                .value_name("DIR")
+                .help("Save the frames as images in DIR instead of playing them")
+                .requires("visualize"),
+        )
+        .arg(
+            Arg::new("frame-format")
+                .long("frame-format")
+                .help("The image format for --frames")
+                .value_parser(clap::builder::PossibleValuesParser::new(ImageFormat::NAMES))
+                .default_value("png")
+                .requires("frames"),
+        )
+        .arg(
+            Arg::new("log")
+                .long("log")
+                .value_name("[YEAR:]DAY:LEVEL")
+                .help(
+                    "Log what day DAY logs up to LEVEL, such as `8:debug`, whatever RUST_LOG \
+                     says; without a YEAR, day DAY of every year",
+                )
+                .value_parser(clap::builder::ValueParser::new(DayFilter::from_str))
+                .global(true)
+                .action(clap::ArgAction::Append),
+        )
+        .arg(
+            Arg::new("log-dir")
+                .long("log-dir")
+                .value_name("DIR")
+                .help("Write what each day logs to DIR/YYYY/dayNN.log rather than stderr")
+                .global(true),
+        )
+        .arg(
+            Arg::new("alloc")
+                .long("alloc")
+                .help("Count the allocations of parsing and each part [requires the alloc-profile feature]")
+                .conflicts_with_all(["all", "parallel", "verify", "compare-variants", "variant", "bench"])
+                .action(clap::ArgAction::SetTrue),
+        )
+        .subcommand(
+            Command::new("new")
+                .about("Generate the source, example and input placeholder of a new day")
+                .arg(
+                    Arg::new("day")
+                        .value_parser(clap::value_parser!(usize))
+                        .required(true),
+                )
+                .arg(
+                    Arg::new("year")
+                        .long("year")
+                        .value_parser(clap::value_parser!(usize))
+                        .help("The year of the new day [default: the latest]"),
+                )
+                .arg(
+                    Arg::new("flavour")
+                        .long("flavour")
+                        .help("The shape of input the template parses")
+                        .value_parser(clap::builder::PossibleValuesParser::new(Flavour::names()))
+                        .default_value("lines"),
+                ),
+        )
+        .subcommand(
+            Command::new("watch")
+                .about("Solve a day on its example and input again whenever either file changes")
+                .arg(
+                    Arg::new("day")
+                        .value_parser(clap::value_parser!(usize))
+                        .required(true),
+                )
+                .arg(
+                    Arg::new("year")
+                        .long("year")
+                        .value_parser(clap::value_parser!(usize))
+                        .help("The year of the day [default: the latest]"),
+                )
+                .arg(
+                    Arg::new("interval")
+                        .long("interval")
+                        .value_name("DURATION")
+                        .help("How often to check the files for changes")
+                        .value_parser(parse_duration)
+                        .default_value("300ms"),
+                ),
+        )
+        .subcommand(
+            Command::new("repl")
+                .about("Parse a day's input once, then solve its parts interactively")
+                .arg(
+                    Arg::new("day")
+                        .value_parser(clap::value_parser!(usize))
+                        .required(true),
+                )
+                .arg(
+                    Arg::new("year")
+                        .long("year")
+                        .value_parser(clap::value_parser!(usize))
+                        .help("The year of the day [default: the latest]"),
+                )
+                .arg(
+                    Arg::new("example")
+                        .long("example")
+                        .help("Start with the first example rather than the real input")
+                        .action(clap::ArgAction::SetTrue),
+                ),
+        )
+        .subcommand(
+            Command::new("generate")
+                .about("Write a random input for a day, of any size, to stress-test its solvers")
+                .arg(
+                    Arg::new("day")
+                        .value_parser(clap::value_parser!(usize))
+                        .required(true),
+                )
+                .arg(
+                    Arg::new("year")
+                        .long("year")
+                        .value_parser(clap::value_parser!(usize))
+                        .help("The year of the day [default: the latest]"),
+                )
+                .arg(
+                    Arg::new("size")
+                        .long("size")
+                        .value_name("N")
+                        .help("How big an input, in the day's own unit such as lines or rows")
+                        .value_parser(clap::value_parser!(usize))
+                        .required(true),
+                )
+                .arg(
+                    Arg::new("seed")
+                        .long("seed")
+                        .value_name("S")
+                        .help("The seed of the random numbers; the same seed gives the same input")
+                        .value_parser(clap::value_parser!(u64))
+                        .default_value("0"),
+                )
+                .arg(
+                    Arg::new("output")
+                        .long("output")
+                        .short('o')
+                        .value_name("PATH")
+                        .help("Write the input to this file rather than to stdout"),
+                ),
+        )
+        .subcommand(
+            Command::new("validate")
+                .about("Check that every input and example file parses, without solving")
+                .arg(
+                    Arg::new("day")
+                        .value_parser(clap::value_parser!(usize))
+                        .help("Only check the files of this day"),
+                )
+                .arg(
+                    Arg::new("year")
+                        .long("year")
+                        .value_parser(clap::value_parser!(usize))
+                        .help("Only check the files of this year [default: every year]"),
+                )
+                .arg(
+                    Arg::new("strict")
+                        .long("strict")
+                        .help("Fail on whitespace warnings as well as on parse failures")
+                        .action(clap::ArgAction::SetTrue),
+                ),
+        )
+}
+
+fn new_day(matches: &ArgMatches) -> Result<(), Report> {
+    let day = *matches.get_one::<usize>("day").unwrap();
+    let year = match matches.get_one::<usize>("year") {
+        Some(year) => *year,
+        None => *get_years().keys().next_back().unwrap(),
+    };
+    let flavour: Flavour = matches.get_one::<String>("flavour").unwrap().parse()?;
+    let scaffold = Scaffold::locate(year, day)?;
+    let new_year = !scaffold.modules.exists();
+    scaffold.create(flavour)?;
+    println!("Created {} day {}:", year, day);
+    for path in [&scaffold.source, &scaffold.example, &scaffold.input] {
+        println!("  {}", path.display());
+    }
+    println!("and declared it in {}.", scaffold.modules.display());
+    if new_year {
+        println!("Declare `mod y{year};` in advent2025-lib/src/lib.rs for the new year.");
+    }
+    println!("It is registered by the next build, and its tests fail until it is solved.");
+    Ok(())
+}
+
+/// The year and day of the positional arguments, which are `YEAR DAY`, `YEAR`, or a lone `DAY`
+/// of the latest year when no year has its number.
+fn year_and_day(
+    years: &BTreeMap<usize, Year>,
+    first: Option<usize>,
+    second: Option<usize>,
+) -> (Option<usize>, Option<usize>) {
+    match (first, second) {
+        (Some(day), None) if !years.contains_key(&day) => {
+            (years.keys().next_back().copied(), Some(day))
+        }
+        positionals => positionals,
+    }
+}
+
+/// The year and day named by a subcommand's `--year` and `day` arguments.
+fn find_day<'a>(
+    years: &'a BTreeMap<usize, Year>,
+    matches: &ArgMatches,
+) -> Result<(usize, usize, &'a dyn DayTrait), Report> {
+    let day_num = *matches.get_one::<usize>("day").unwrap();
+    let year = match matches.get_one::<usize>("year") {
+        Some(year) => years
+            .get(year)
+            .ok_or_else(|| eyre!("no solutions for year {year}"))?,
+        None => years.values().next_back().unwrap(),
+    };
+    let day = year
+        .days
+        .get(&day_num)
+        .ok_or_else(|| eyre!("no solution for {} day {day_num}", year.year))?;
+    Ok((year.year, day_num, day.as_ref()))
+}
+
+fn watch_day(matches: &ArgMatches) -> Result<(), Report> {
+    let years = get_years();
+    let (year, day_num, day) = find_day(&years, matches)?;
+    let interval = *matches.get_one::<Duration>("interval").unwrap();
+    watch::watch(year, day_num, day, interval)
+}
+
+fn repl_day(matches: &ArgMatches) -> Result<(), Report> {
+    let years = get_years();
+    let (year, day_num, day) = find_day(&years, matches)?;
+    repl::repl(year, day_num, day, matches.get_flag("example"))
+}
+
+fn generate_input(matches: &ArgMatches) -> Result<(), Report> {
+    let years = get_years();
+    let (year, day_num, day) = find_day(&years, matches)?;
+    let generator = day
+        .generator()
+        .ok_or_else(|| eyre!("{year} day {day_num} has no input generator"))?;
+    let size = *matches.get_one::<usize>("size").unwrap();
+    let seed = *matches.get_one::<u64>("seed").unwrap();
+    let input = generator.generate(size, seed);
+    match matches.get_one::<String>("output") {
+        Some(path) => {
+            std::fs::write(path, &input).map_err(|e| eyre!("{path} ({e})"))?;
+            eprintln!(
+                "Wrote {year} day {day_num} with {size} {} from seed {seed} to {path}",
+                generator.unit
+            );
+        }
+        None => match std::io::stdout().write_all(input.as_bytes()) {
+            // Whatever reads the input, such as `head`, may not read it all.
+            Err(e) if e.kind() == ErrorKind::BrokenPipe => {}
+            written => written?,
+        },
+    }
+    Ok(())
+}
+
+fn validate_files(matches: &ArgMatches) -> Result<(), Report> {
+    let mut years = get_years();
+    if let Some(year) = matches.get_one::<usize>("year") {
+        let year = years
+            .remove(year)
+            .ok_or_else(|| eyre!("no solutions for year {year}"))?;
+        years = BTreeMap::from([(year.year, year)]);
+    }
+    let day = matches.get_one::<usize>("day").copied();
+    validate::validate(&years, day, matches.get_flag("strict"))
+}
+
+fn parse_param(param: &str) -> Result<(String, String), String> {
+    let (key, value) = param
+        .split_once('=')
+        .ok_or_else(|| format!("expected KEY=VALUE, found `{param}`"))?;
+    Ok((key.trim().to_string(), value.trim().to_string()))
+}
+
+/// Parses a duration such as `10s`, `500ms` or `2m`, in seconds if it has no unit.
+fn parse_duration(duration: &str) -> Result<Duration, String> {
+    let split = duration
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(duration.len());
+    let (value, unit) = duration.split_at(split);
+    let value: f64 = value
+        .parse()
+        .map_err(|_| format!("expected a duration such as `10s`, found `{duration}`"))?;
+    let seconds = match unit {
+        "ms" => value / 1000.0,
+        "" | "s" => value,
+        "m" => value * 60.0,
+        _ => return Err(format!("unknown unit `{unit}`, expected `ms`, `s` or `m`")),
+    };
+    Ok(Duration::from_secs_f64(seconds))
+}
+
+/// The example's own parameter overrides, followed by those given on the command line.
+fn overrides<'a>(
+    example: &'a [(&'a str, &'a str)],
+    params: &'a [(String, String)],
+) -> Vec<(&'a str, &'a str)> {
+    example
+        .iter()
+        .copied()
+        .chain(
+            params
+                .iter()
+                .map(|(key, value)| (key.as_str(), value.as_str())),
+        )
+        .collect()
+}
+
+/// The inputs to solve the parts of a day on: one that both share, or with `--example`, the
+/// own example of each part where the day has two.
+enum PartInputs {
+    Same(String, &'static ParamOverrides<'static>),
+    Different([&'static Example; 2]),
+}
+
+impl PartInputs {
+    /// The input of `part`, with the parameters of its example, if any.
+    fn part(&self, part: Part) -> (&str, &'static ParamOverrides<'static>) {
+        let example = match (self, part) {
+            (PartInputs::Same(input, params), _) => return (input, params),
+            (PartInputs::Different([first, _]), Part::First) => first,
+            (PartInputs::Different([_, second]), Part::Second) => second,
+        };
+        (example.input, example.params)
+    }
+
+    /// Runs `run` on the input both parts share, or else on the input of each part, and keeps
+    /// part 1 of the first run and part 2 of the second with `merge`.
+    fn run<T>(
+        &self,
+        params: &[(String, String)],
+        mut run: impl FnMut(&str, &ParamOverrides) -> Result<T, Report>,
+        merge: impl FnOnce(T, T) -> T,
+    ) -> Result<T, Report> {
+        match self {
+            PartInputs::Same(input, example_params) => {
+                run(input, &overrides(example_params, params))
+            }
+            PartInputs::Different([first, second]) => {
+                let first = run(first.input, &overrides(first.params, params))?;
+                let second = run(second.input, &overrides(second.params, params))?;
+                Ok(merge(first, second))
+            }
+        }
+    }
+}
+
+/// Solves `part` with the named solver, or with the default one if only the other part has
+/// that variant.
+fn solve_variant(
+    day: &dyn DayTrait,
+    part: Part,
+    variant: &str,
+    input: &str,
+    params: &ParamOverrides,
+    ctx: &Context,
+) -> ParseResult<AnswerResult> {
+    day.calc_variant(part, variant, input, params, ctx)
+        .or_else(|| day.calc_variant(part, DEFAULT_VARIANT, input, params, ctx))
+        .expect("every day has a default solver")
+}
+
+/// Fails unless at least one of `days` has a solver named `variant` for either part, listing
+/// the names they do have. The other days, and parts, are solved with their default solver.
+fn check_variant(variant: &str, days: &[&dyn DayTrait]) -> Result<(), Report> {
+    let mut known: Vec<&str> = Vec::new();
+    for day in days {
+        for name in day
+            .variants(Part::First)
+            .into_iter()
+            .chain(day.variants(Part::Second))
+        {
+            if !known.contains(&name) {
+                known.push(name);
+            }
+        }
+    }
+    if known.contains(&variant) {
+        return Ok(());
+    }
+    Err(eyre!(
+        "no solver variant named {variant:?}, expected one of {}",
+        known.join(", ")
+    ))
 }
 
-fn print_day<O: std::fmt::Display>(
+/// Prints every variant's answer, and returns whether they all agree, which they cannot if
+/// the input failed to read or parse.
+fn print_variants(
     day_num: usize,
-    display: (&'static str, &'static str),
-    result: (O, O),
-) {
+    result: Result<(VariantAnswers, VariantAnswers), Report>,
+) -> bool {
     println!("Day {}", day_num);
+    let (part1, part2) = match result {
+        Ok(parts) => parts,
+        Err(e) => {
+            println!("{}", e.to_string().red());
+            println!();
+            return false;
+        }
+    };
+    let mut agree = true;
+    for (part_num, answers) in [(1, part1), (2, part2)] {
+        let (_, reference) = &answers[0];
+        for (name, answer) in &answers {
+            let rendered = match answer {
+                Ok(answer) => answer.to_string(),
+                Err(e) => format!("failed: {e}"),
+            };
+            if answer == reference {
+                println!("Part {}: {:<12} {}", part_num, name, rendered);
+            } else {
+                agree = false;
+                println!(
+                    "Part {}: {:<12} {} {}",
+                    part_num,
+                    name,
+                    rendered.red(),
+                    "(disagrees)".red().bold()
+                );
+            }
+        }
+    }
+    println!();
+    agree
+}
+
+fn print_stats_header() {
     println!(
-        "Part 1: {}",
-        display.0.replace("{answer}", &result.0.to_string())
+        "{:<8} {:>12} {:>12} {:>12} {:>12}",
+        "", "min", "median", "mean", "stddev"
     );
+}
+
+fn print_stats(samples: &[PhaseTimes]) {
+    for phase in Phase::ALL {
+        let stats = phase_stats(samples, phase);
+        let row = format!(
+            "{:<8} {:>12.3?} {:>12.3?} {:>12.3?} {:>12.3?}",
+            phase.name(),
+            stats.min,
+            stats.median,
+            stats.mean,
+            stats.stddev
+        );
+        match phase {
+            Phase::Total => println!("{}", row.bold()),
+            _ => println!("{}", row),
+        }
+    }
+}
+
+/// Prints every phase compared against the baseline, and returns how many regressed past
+/// `threshold` percent.
+fn print_comparisons(name: &str, comparisons: &[Comparison], threshold: f64) -> usize {
+    println!("{}", format!("Compared with baseline {name:?}").bold());
+    let mut printed_day = None;
+    for comparison in comparisons {
+        if printed_day != Some((comparison.year, comparison.day)) {
+            println!("{} day {}", comparison.year, comparison.day);
+            printed_day = Some((comparison.year, comparison.day));
+        }
+        let row = format!(
+            "{:<8} {:>12.3?} -> {:>12.3?} {:>+8.1}% {}",
+            comparison.phase.name(),
+            comparison.before.mean,
+            comparison.after.mean,
+            comparison.percent(),
+            comparison.change
+        );
+        match comparison.change {
+            Change::Regressed if comparison.exceeds(threshold) => println!("{}", row.red().bold()),
+            Change::Regressed => println!("{}", row.yellow()),
+            Change::Improved => println!("{}", row.green()),
+            Change::Unchanged => println!("{}", row),
+        }
+    }
+    println!();
+    comparisons
+        .iter()
+        .filter(|comparison| comparison.exceeds(threshold))
+        .count()
+}
+
+#[cfg(feature = "alloc-profile")]
+fn print_allocs(probe: &alloc::AllocProbe) {
     println!(
-        "Part 2: {}",
-        display.1.replace("{answer}", &result.1.to_string())
+        "{:<8} {:>12} {:>12} {:>12}",
+        "", "allocations", "bytes", "peak live"
     );
-    println!();
+    for (phase, stats) in [
+        (Phase::Parse, probe.parse),
+        (Phase::Part1, probe.part1),
+        (Phase::Part2, probe.part2),
+    ] {
+        println!(
+            "{:<8} {:>12} {:>12} {:>12}",
+            phase.name(),
+            stats.allocations,
+            alloc::format_bytes(stats.bytes),
+            alloc::format_bytes(stats.peak)
+        );
+    }
 }
 
-fn main() -> Result<(), Report> {
-    setup()?;
+/// What a job of [`run_parallel`] produced: a whole day, or one part of it.
+enum Outcome {
+    Day(Result<Solved, Report>),
+    Part(Result<AnswerResult, Report>),
+}
 
-    println!("{}", "Advent Of Code 2022".bold().blue());
-    println!();
+type SolveDay<'a> = dyn Fn(&dyn DayTrait, &Context) -> Result<Solved, Report> + Sync + 'a;
+type SolvePart<'a> =
+    dyn Fn(&dyn DayTrait, Part, &Context) -> Result<AnswerResult, Report> + Sync + 'a;
+
+/// Runs the selected days on `workers` threads, printing each day as soon as it and every day
+/// before it have finished, then the wall-clock time against the time summed over the jobs.
+///
+/// With `split_parts`, the parts of a day are separate jobs, which both parse the input.
+fn run_parallel(
+    selected: &[(usize, usize, &dyn DayTrait)],
+    workers: usize,
+    split_parts: bool,
+    solve_day: &SolveDay,
+    solve_part: &SolvePart,
+    printer: &mut Printer,
+) {
+    let parts: &[Option<Part>] = if split_parts {
+        &[Some(Part::First), Some(Part::Second)]
+    } else {
+        &[None]
+    };
+    let multi = MultiProgress::new();
+    let mut jobs: Vec<Job<Outcome>> = Vec::new();
+    for (year, day_num, day) in selected {
+        for part in parts {
+            let multi = &multi;
+            jobs.push(match part {
+                Some(part) => Box::new(move || {
+                    let bar = Bar::start(
+                        multi,
+                        format!("{year} day {day_num} part {}", *part as usize + 1),
+                    );
+                    let answer = isolated(|| solve_part(*day, *part, &Context::new(&bar)))
+                        .unwrap_or_else(|panic| Err(panic.into()));
+                    bar.finish(multi);
+                    Outcome::Part(answer)
+                }),
+                None => Box::new(move || {
+                    let bar = Bar::start(multi, format!("{year} day {day_num}"));
+                    let result = isolated(|| solve_day(*day, &Context::new(&bar)))
+                        .unwrap_or_else(|panic| Err(panic.into()));
+                    bar.finish(multi);
+                    Outcome::Day(result)
+                }),
+            });
+        }
+    }
+
+    let overall = multi.add(
+        ProgressBar::new(jobs.len() as u64)
+            .with_style(ProgressStyle::with_template("{wide_bar} {pos}/{len} {msg}").unwrap()),
+    );
+    // The part of a day that finished first, while the other one is still running.
+    let mut halves: HashMap<usize, (usize, Result<AnswerResult, Report>)> = HashMap::new();
+    let mut in_order = InOrder::default();
+    let mut busy = Duration::ZERO;
+    let mut running = 0;
+    let started = Instant::now();
+    scheduler::run(jobs, workers, |event| match event {
+        Event::Started(_) => {
+            running += 1;
+            overall.set_message(format!("{running} running"));
+        }
+        Event::Finished {
+            job,
+            output,
+            elapsed,
+        } => {
+            busy += elapsed;
+            running -= 1;
+            overall.set_message(format!("{running} running"));
+            overall.inc(1);
+            let idx = job / parts.len();
+            let result = match output {
+                Outcome::Day(result) => result,
+                Outcome::Part(answer) => match halves.remove(&idx) {
+                    None => {
+                        halves.insert(idx, (job, answer));
+                        return;
+                    }
+                    Some((other_job, other)) => {
+                        let (part1, part2) = if job < other_job {
+                            (answer, other)
+                        } else {
+                            (other, answer)
+                        };
+                        part1.and_then(|part1| {
+                            Ok(Solved {
+                                part1,
+                                part2: part2?,
+                                times: None,
+                            })
+                        })
+                    }
+                },
+            };
+            let (year, day_num, day) = selected[idx];
+            for record in in_order.push(idx, DayRecord::new(year, day_num, day, result)) {
+                multi.suspend(|| printer.print(record));
+            }
+        }
+    });
+    overall.finish_and_clear();
 
+    if printer.is_text() {
+        let wall = started.elapsed();
+        println!(
+            "{}",
+            format!(
+                "{} days in {:.3?} on {} worker{}, {:.3?} of CPU time summed over the jobs ({:.1}x)",
+                selected.len(),
+                wall,
+                workers,
+                if workers == 1 { "" } else { "s" },
+                busy,
+                busy.as_secs_f64() / wall.as_secs_f64()
+            )
+            .bold()
+        );
+        println!();
+    }
+}
+
+fn main() -> Result<(), Report> {
     let matches = build_cli().get_matches();
-    let puzzle = matches.get_one::<usize>("puzzle").copied();
+    setup(&matches)?;
+
+    if let Some(matches) = matches.subcommand_matches("new") {
+        return new_day(matches);
+    }
+    if let Some(matches) = matches.subcommand_matches("watch") {
+        return watch_day(matches);
+    }
+    if let Some(matches) = matches.subcommand_matches("repl") {
+        return repl_day(matches);
+    }
+    if let Some(matches) = matches.subcommand_matches("generate") {
+        return generate_input(matches);
+    }
+    if let Some(matches) = matches.subcommand_matches("validate") {
+        return validate_files(matches);
+    }
+    let years = get_years();
+    let (year, puzzle) = year_and_day(
+        &years,
+        matches.get_one::<usize>("year").copied(),
+        matches.get_one::<usize>("puzzle").copied(),
+    );
     let all = matches.get_flag("all");
     let parallel = matches.get_flag("parallel");
     let example = matches.get_flag("example");
-    let days = get_days();
+    let variant = matches.get_one::<String>("variant").cloned();
+    let compare_variants = matches.get_flag("compare-variants");
+    let params: Vec<(String, String)> = matches
+        .get_many::<(String, String)>("param")
+        .unwrap_or_default()
+        .cloned()
+        .collect();
+    // The checking modes run every day unless given one, but --input holds a single day's input.
+    let checking = ["verify", "bench", "compare-variants", "alloc"]
+        .into_iter()
+        .find(|mode| matches.get_flag(mode));
+    if let Some(mode) = checking
+        && puzzle.is_none()
+        && matches.contains_id("input")
+    {
+        return Err(eyre!(
+            "--input holds the input of a single day, so --{mode} needs a day to run with it"
+        ));
+    }
+    if let Some(year) = year
+        && !years.contains_key(&year)
+    {
+        let known: Vec<String> = years.keys().map(usize::to_string).collect();
+        return Err(eyre!(
+            "no solutions for year {year}, expected one of {}",
+            known.join(", ")
+        ));
+    }
+    if let (Some(year), Some(day_num)) = (year, puzzle)
+        && !years[&year].days.contains_key(&day_num)
+    {
+        return Err(eyre!("no solution for {year} day {day_num}"));
+    }
 
-    let get_result_pair = move |day: &dyn DayTrait| -> (String, String) {
-        if example {
-            match day.examples() {
-                PrimaryExample::Same(example) => {
-                    day.both(example).expect("example should be valid")
-                }
-                PrimaryExample::Different([first, second]) => (
-                    day.calc(Part::First, first).unwrap(),
-                    day.calc(Part::Second, second).unwrap(),
-                ),
+    let source: Arc<dyn InputSource> = match (
+        matches.get_one::<String>("input"),
+        matches.get_one::<String>("input-name"),
+    ) {
+        (Some(path), _) if path == "-" => Arc::new(StdinInput),
+        (Some(path), _) => Arc::new(PathInput(path.into())),
+        (None, Some(name)) => {
+            let mut dirs: Vec<PathBuf> = std::env::var_os(INPUT_DIR_VAR)
+                .map(PathBuf::from)
+                .into_iter()
+                .collect();
+            dirs.extend(["inputs".into(), "../inputs".into()]);
+            Arc::new(NamedInput {
+                name: name.clone(),
+                dirs,
+            })
+        }
+        (None, None) => Arc::new(ChainInput::standard()),
+    };
+
+    let get_inputs = move |day: &dyn DayTrait| -> Result<PartInputs, Report> {
+        if !example {
+            return Ok(PartInputs::Same(day.input_from(source.as_ref())?, &[]));
+        }
+        Ok(match day.examples() {
+            PrimaryExample::Same(example) => {
+                PartInputs::Same(example.input.to_string(), example.params)
+            }
+            PrimaryExample::Different(examples) => PartInputs::Different(examples),
+        })
+    };
+
+    // Checking modes default to every day of the year, or of every year, rather than the latest.
+    let in_year = |selected: &Year| year.is_none_or(|year| year == selected.year);
+    let selected: Vec<(usize, usize, &Box<dyn DayTrait>)> = years
+        .values()
+        .filter(|selected| in_year(selected))
+        .flat_map(|selected| {
+            selected
+                .days
+                .iter()
+                .filter(|(day_num, _)| all || parallel || puzzle.is_none_or(|d| d == **day_num))
+                .map(|(day_num, day)| (selected.year, *day_num, day))
+        })
+        .collect();
+    // Without --all or --parallel, the one day to solve: the given one, or the year's latest.
+    let single = {
+        let year = match year {
+            Some(year) => &years[&year],
+            None => years.values().next_back().unwrap(),
+        };
+        let (day_num, day) = match puzzle {
+            Some(day_num) => (day_num, &year.days[&day_num]),
+            None => {
+                let (last_day_num, last_day) = year.days.iter().next_back().unwrap();
+                (*last_day_num, last_day)
             }
+        };
+        (year.year, day_num, day)
+    };
+    if let Some(variant) = &variant {
+        let days: Vec<&dyn DayTrait> = if all || parallel {
+            selected.iter().map(|(_, _, day)| day.as_ref()).collect()
         } else {
-            day.both(&day.input()).expect("invalid input")
+            vec![single.2.as_ref()]
+        };
+        check_variant(variant, &days)?;
+    }
+    let mut printed_year = None;
+
+    if matches.get_flag("verify") {
+        let answers = match matches.get_one::<String>("answers") {
+            Some(path) => Answers::from_path(Path::new(path))?,
+            None => Answers::load()?,
+        };
+        let mut failed = false;
+        for (year, day_num, day) in selected {
+            print_year(&mut printed_year, year);
+            println!("Day {}", day_num);
+            // --verify conflicts with --example, so both parts share the input.
+            let verdicts = match get_inputs(day.as_ref()) {
+                Ok(inputs) => {
+                    let (input, _) = inputs.part(Part::First);
+                    answers::verify(year, day_num, day.as_ref(), input, &answers)
+                }
+                Err(e) => [Verdict::Error(e.to_string()), Verdict::Error(e.to_string())],
+            };
+            for (part_num, verdict) in [1, 2].into_iter().zip(verdicts) {
+                failed |= verdict.is_failure();
+                let rendered = match verdict {
+                    Verdict::Pass => verdict.to_string().green(),
+                    Verdict::Missing { .. } => verdict.to_string().yellow(),
+                    Verdict::Fail { .. } | Verdict::Error(_) => verdict.to_string().red(),
+                };
+                println!("Part {}: {}", part_num, rendered);
+            }
+            println!();
         }
-    };
+        if failed {
+            return Err(eyre!("some answers do not match the answers file"));
+        }
+        return Ok(());
+    }
 
-    if all {
-        for (day_num, day) in days.into_iter() {
-            print_day(day_num, day.display(), get_result_pair(day.as_ref()));
+    if compare_variants {
+        let mut agree = true;
+        for (year, day_num, day) in selected {
+            if day.variants(Part::First).len() + day.variants(Part::Second).len() <= 2 {
+                continue;
+            }
+            let result = get_inputs(day.as_ref()).and_then(|inputs| {
+                inputs.run(
+                    &params,
+                    |input, overrides| Ok(day.all_variants(input, overrides)?),
+                    |(part1, _), (_, part2)| (part1, part2),
+                )
+            });
+            print_year(&mut printed_year, year);
+            agree &= print_variants(day_num, result);
         }
-    } else if parallel {
-        let threads = get_days().into_iter().map(|(day_num, day)| {
-            println!("Spawn day {}", day_num);
-            std::thread::spawn(move || (day_num, day.display(), get_result_pair(day.as_ref())))
-        });
-        std::thread::yield_now();
-        std::thread::sleep(std::time::Duration::from_millis(50));
+        if !agree {
+            return Err(eyre!("solver variants disagree or failed to run"));
+        }
+        return Ok(());
+    }
+
+    if matches.get_flag("bench") {
+        let default = BenchConfig::default();
+        let config = BenchConfig {
+            warmup: matches.get_one("warmup").copied().unwrap_or(default.warmup),
+            iterations: matches
+                .get_one("iterations")
+                .copied()
+                .unwrap_or(default.iterations),
+        };
+        let runs = config.warmup + config.iterations;
+        let progress = ProgressBar::new((selected.len() * runs) as u64)
+            .with_style(ProgressStyle::with_template("{wide_bar} {pos}/{len} {msg}").unwrap());
+        let mut report = BenchReport {
+            config,
+            days: Vec::new(),
+        };
+        let mut failures = Vec::new();
+        for (year, day_num, day) in selected {
+            progress.set_message(format!("{year} day {day_num}"));
+            let before = progress.position();
+            // A day with an example per part runs twice as often, with each run counted as half.
+            let result = get_inputs(day.as_ref()).and_then(|inputs| {
+                let per_run = match inputs {
+                    PartInputs::Same(..) => 1,
+                    PartInputs::Different(_) => 2,
+                };
+                let mut runs = 0;
+                inputs.run(
+                    &params,
+                    |input, overrides| {
+                        Ok(bench_day(
+                            year,
+                            day_num,
+                            day.as_ref(),
+                            input,
+                            overrides,
+                            &config,
+                            || {
+                                runs += 1;
+                                if runs % per_run == 0 {
+                                    progress.inc(1);
+                                }
+                            },
+                        )?)
+                    },
+                    DayBench::merge_parts,
+                )
+            });
+            match result {
+                Ok(bench) => report.days.push(bench),
+                Err(e) => {
+                    progress.set_position(before + runs as u64);
+                    failures.push((year, day_num, e));
+                }
+            }
+        }
+        progress.finish_and_clear();
+
+        for bench in &report.days {
+            print_year(&mut printed_year, bench.year);
+            println!("Day {}", bench.day);
+            for (part_num, answer) in [(1, &bench.answers.0), (2, &bench.answers.1)] {
+                if let Err(e) = answer {
+                    println!("Part {}: {}", part_num, format!("failed: {e}").red());
+                }
+            }
+            print_stats_header();
+            print_stats(&bench.samples);
+            println!();
+        }
+        for (year, day_num, e) in &failures {
+            print_year(&mut printed_year, *year);
+            println!("Day {}", day_num);
+            println!("{}", e.to_string().red());
+            println!();
+        }
+        println!("{}", "All days".bold());
+        print_stats_header();
+        print_stats(&report.total());
         println!();
-        for thread in threads {
-            let (day_num, display, (part1, part2)) = thread.join().unwrap();
-            print_day(day_num, display, (part1, part2));
+
+        let mut regressions = 0;
+        if let Some(name) = matches.get_one::<String>("baseline") {
+            let baseline = Baseline::load(Path::new(BASELINE_DIR), name)?;
+            let threshold = matches.get_one("threshold").copied().unwrap_or(5.0);
+            regressions = print_comparisons(name, &baseline.compare(&report), threshold);
         }
-    } else if !(all || parallel) {
-        let (day_num, day): (usize, _) = match puzzle {
-            None => {
-                let (last_day_num, last_day) = days.iter().next_back().unwrap();
-                (*last_day_num, last_day)
+        if let Some(path) = matches.get_one::<String>("bench-json") {
+            std::fs::write(path, format!("{:#}\n", report.to_json()))?;
+            println!("Wrote {}", path);
+        }
+        if let Some(name) = matches.get_one::<String>("save-baseline") {
+            let path = Baseline::save(&report, Path::new(BASELINE_DIR), name)?;
+            println!("Saved baseline {:?} to {}", name, path.display());
+        }
+        if regressions > 0 {
+            return Err(eyre!("{regressions} timings regressed past the threshold"));
+        }
+        return Ok(());
+    }
+
+    if matches.get_flag("alloc") {
+        #[cfg(not(feature = "alloc-profile"))]
+        return Err(eyre!(
+            "--alloc needs the allocator from the alloc-profile feature, build with \
+             `--features alloc-profile`"
+        ));
+        #[cfg(feature = "alloc-profile")]
+        {
+            for (year, day_num, day) in selected {
+                print_year(&mut printed_year, year);
+                println!("Day {}", day_num);
+                let result = get_inputs(day.as_ref()).and_then(|inputs| {
+                    inputs.run(
+                        &params,
+                        |input, overrides| {
+                            let mut probe = alloc::AllocProbe::default();
+                            let _ = day.probed(input, overrides, &Context::none(), &mut probe)?;
+                            Ok(probe)
+                        },
+                        alloc::AllocProbe::merge_parts,
+                    )
+                });
+                match result {
+                    Ok(probe) => print_allocs(&probe),
+                    Err(e) => println!("{}", e.to_string().red()),
+                }
+                println!();
+            }
+            return Ok(());
+        }
+    }
+
+    let timeout = matches.get_one::<Duration>("timeout").copied();
+    let get_result_pair = |day: &dyn DayTrait, ctx: &Context| -> Result<Solved, Report> {
+        let cancel = timeout.map(CancelToken::with_timeout).unwrap_or_default();
+        let ctx = &ctx.with_cancel(&cancel);
+        let inputs = get_inputs(day)?;
+        match (&variant, &inputs) {
+            (None, PartInputs::Same(input, example_params)) => {
+                let run = timed(day, input, &overrides(example_params, &params), ctx)?;
+                Ok(Solved {
+                    part1: run.part1,
+                    part2: run.part2,
+                    times: Some(run.times),
+                })
+            }
+            (variant, inputs) => {
+                let variant = variant.as_deref().unwrap_or(DEFAULT_VARIANT);
+                let solve = |part| {
+                    let (input, example_params) = inputs.part(part);
+                    let overrides = overrides(example_params, &params);
+                    solve_variant(day, part, variant, input, &overrides, ctx)
+                };
+                Ok(Solved {
+                    part1: solve(Part::First)?,
+                    part2: solve(Part::Second)?,
+                    times: None,
+                })
             }
-            Some(day_num) => (day_num, days.get(&day_num).unwrap()),
+        }
+    };
+    // Solves a single part, for running the parts of a day as separate jobs.
+    let get_part =
+        |day: &dyn DayTrait, part: Part, ctx: &Context| -> Result<AnswerResult, Report> {
+            let inputs = get_inputs(day)?;
+            let (input, example_params) = inputs.part(part);
+            let overrides = overrides(example_params, &params);
+            let variant = variant.as_deref().unwrap_or(DEFAULT_VARIANT);
+            Ok(solve_variant(day, part, variant, input, &overrides, ctx)?)
         };
-        print_day(day_num, day.display(), get_result_pair(day.as_ref()));
+
+    let format: Format = matches
+        .get_one::<String>("format")
+        .unwrap()
+        .parse()
+        .unwrap();
+    let mut printer = Printer::new(format);
+    let multi = MultiProgress::new();
+    let visualize = matches.get_flag("visualize");
+    let recorder = Recorder::default();
+    // Solves a day, showing what it reports while it runs, and reporting a panic as its result.
+    let watched = |year, day_num, day: &dyn DayTrait| {
+        let bar = Bar::start(&multi, format!("{year} day {day_num}"));
+        let ctx = if visualize {
+            Context::new(&bar).with_visualizer(&recorder)
+        } else {
+            Context::new(&bar)
+        };
+        let result =
+            isolated(|| get_result_pair(day, &ctx)).unwrap_or_else(|panic| Err(panic.into()));
+        bar.finish(&multi);
+        result
+    };
+    if all {
+        for (year, day_num, day) in selected {
+            let result = watched(year, day_num, day.as_ref());
+            printer.print(DayRecord::new(year, day_num, day.as_ref(), result));
+        }
+    } else if parallel {
+        let workers = matches
+            .get_one::<usize>("jobs")
+            .copied()
+            .unwrap_or_else(|| std::thread::available_parallelism().map_or(1, NonZeroUsize::get));
+        let selected: Vec<(usize, usize, &dyn DayTrait)> = selected
+            .iter()
+            .map(|(year, day_num, day)| (*year, *day_num, day.as_ref()))
+            .collect();
+        run_parallel(
+            &selected,
+            workers,
+            matches.get_flag("split-parts"),
+            &get_result_pair,
+            &get_part,
+            &mut printer,
+        );
+    } else {
+        let (year, day_num, day) = single;
+        let result = watched(year, day_num, day.as_ref());
+        printer.print(DayRecord::new(year, day_num, day.as_ref(), result));
+        if visualize {
+            let title = format!("{year} day {day_num}: {}", day.title());
+            show_frames(&matches, &title, recorder.into_frames())?;
+        }
     }
+    printer.finish();
 
     Ok(())
 }
 
-fn setup() -> Result<(), Report> {
+/// Plays the frames a day emitted, or saves them where `--cast` and `--frames` say.
+fn show_frames(matches: &ArgMatches, title: &str, frames: Vec<Frame>) -> Result<(), Report> {
+    if frames.is_empty() {
+        println!("{}", "The day emitted no frames to visualize".yellow());
+        return Ok(());
+    }
+    let fps = matches.get_one::<f64>("fps").copied().unwrap_or(10.0);
+    let cast = matches.get_one::<String>("cast");
+    let dir = matches.get_one::<String>("frames");
+    if let Some(path) = cast {
+        visualize::write_cast(Path::new(path), title, &frames, fps)?;
+        println!("Wrote {} frames to {}", frames.len(), path);
+    }
+    if let Some(dir) = dir {
+        let format: ImageFormat = matches
+            .get_one::<String>("frame-format")
+            .unwrap()
+            .parse()
+            .unwrap();
+        visualize::write_images(Path::new(dir), &frames, format)?;
+        println!("Wrote {} frames to {}", frames.len(), dir);
+    }
+    if cast.is_none() && dir.is_none() {
+        visualize::play(&frames, fps)?;
+    }
+    Ok(())
+}
+
+fn setup(matches: &ArgMatches) -> Result<(), Report> {
     // if std::env::var("RUST_BACKTRACE").is_err() {
     //     std::env::set_var("RUST_BACKTRACE", "1")
     // }
     color_eyre::install()?;
 
-    env_logger::init();
+    let filters = matches
+        .get_many::<DayFilter>("log")
+        .unwrap_or_default()
+        .copied()
+        .collect();
+    let dir = matches.get_one::<String>("log-dir").map(PathBuf::from);
+    DayLogger::install(filters, dir)?;
     log::info!("Starting Logging");
 
     Ok(())
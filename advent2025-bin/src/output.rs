@@ -0,0 +1,395 @@
+//! Run results as text for people, or as JSON, CSV or Markdown for other tools.
+
+use std::str::FromStr;
+use std::time::Duration;
+
+use advent2025_lib::bench::PhaseTimes;
+use advent2025_lib::panic::Panic;
+use advent2025_lib::{AnswerResult, DayTrait, SolveError};
+use color_eyre::Report;
+use colored::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+    Markdown,
+}
+
+impl Format {
+    pub const NAMES: [&str; 4] = ["text", "json", "csv", "markdown"];
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            "markdown" => Ok(Format::Markdown),
+            _ => Err(format!("unknown format `{s}`")),
+        }
+    }
+}
+
+/// Both answers of a day, with how long each phase took where it was timed.
+pub struct Solved {
+    pub part1: AnswerResult,
+    pub part2: AnswerResult,
+    /// `None` when the parts were solved separately, with a variant or on different examples.
+    pub times: Option<PhaseTimes>,
+}
+
+/// The outcome of running one day.
+pub struct DayRecord {
+    pub year: usize,
+    pub day: usize,
+    pub title: &'static str,
+    pub display: (&'static str, &'static str),
+    /// The answers, or why the day could not be read or parsed.
+    pub result: Result<Solved, Report>,
+}
+
+impl DayRecord {
+    pub fn new(
+        year: usize,
+        day_num: usize,
+        day: &dyn DayTrait,
+        result: Result<Solved, Report>,
+    ) -> Self {
+        DayRecord {
+            year,
+            day: day_num,
+            title: day.title(),
+            display: day.display(),
+            result,
+        }
+    }
+
+    /// `ok`, `timeout` if a part ran out of time, `failed` if a part could not be solved,
+    /// `panic` if the solver panicked, or `error` if there was nothing to solve.
+    fn status(&self) -> &'static str {
+        match &self.result {
+            Ok(solved) if solved.part1.is_ok() && solved.part2.is_ok() => "ok",
+            Ok(_) if self.timed_out(1) || self.timed_out(2) => "timeout",
+            Ok(_) => "failed",
+            Err(e) if e.downcast_ref::<Panic>().is_some() => "panic",
+            Err(_) => "error",
+        }
+    }
+
+    /// Whether the part was cancelled by the timeout.
+    fn timed_out(&self, part_num: usize) -> bool {
+        matches!(self.answer(part_num), Some(Err(SolveError::Cancelled)))
+    }
+
+    fn error(&self) -> Option<String> {
+        self.result.as_ref().err().map(Report::to_string)
+    }
+
+    fn answer(&self, part_num: usize) -> Option<&AnswerResult> {
+        let solved = self.result.as_ref().ok()?;
+        Some(if part_num == 1 {
+            &solved.part1
+        } else {
+            &solved.part2
+        })
+    }
+
+    fn raw(&self, part_num: usize) -> Option<&str> {
+        self.answer(part_num)?.as_deref().ok()
+    }
+
+    /// The answer substituted into the day's display sentence.
+    fn rendered(&self, part_num: usize) -> Option<String> {
+        let display = if part_num == 1 {
+            self.display.0
+        } else {
+            self.display.1
+        };
+        Some(display.replace("{answer}", self.raw(part_num)?))
+    }
+
+    fn part_error(&self, part_num: usize) -> Option<String> {
+        self.answer(part_num)?
+            .as_ref()
+            .err()
+            .map(ToString::to_string)
+    }
+
+    fn times(&self) -> Option<PhaseTimes> {
+        self.result.as_ref().ok()?.times
+    }
+}
+
+fn nanos(duration: Duration) -> u64 {
+    duration.as_nanos() as u64
+}
+
+/// Quotes `field` if it would otherwise break the CSV row.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Escapes `cell` so that it stays within its Markdown table cell.
+fn markdown_cell(cell: &str) -> String {
+    cell.replace('|', "\\|").replace('\n', "<br>")
+}
+
+/// Prints each day as it is run, in the chosen format.
+pub struct Printer {
+    format: Format,
+    printed_year: Option<usize>,
+    printed_header: bool,
+    /// Days held back until [`Printer::finish`], for formats printed as a whole.
+    json: Vec<serde_json::Value>,
+}
+
+impl Printer {
+    pub fn new(format: Format) -> Self {
+        Printer {
+            format,
+            printed_year: None,
+            printed_header: false,
+            json: Vec::new(),
+        }
+    }
+
+    pub fn is_text(&self) -> bool {
+        self.format == Format::Text
+    }
+
+    pub fn print(&mut self, record: DayRecord) {
+        match self.format {
+            Format::Text => self.print_text(&record),
+            Format::Json => self.json.push(Self::json(&record)),
+            Format::Csv => self.print_csv(&record),
+            Format::Markdown => self.print_markdown(&record),
+        }
+    }
+
+    pub fn finish(self) {
+        if self.format == Format::Json {
+            println!("{:#}", serde_json::Value::from(self.json));
+        }
+    }
+
+    fn print_text(&mut self, record: &DayRecord) {
+        print_year(&mut self.printed_year, record.year);
+        println!("Day {}", record.day);
+        match &record.result {
+            Ok(_) => {
+                for part_num in [1, 2] {
+                    match (record.rendered(part_num), record.part_error(part_num)) {
+                        (Some(rendered), _) => println!("Part {}: {}", part_num, rendered),
+                        _ if record.timed_out(part_num) => {
+                            println!("Part {}: {}", part_num, "TIMEOUT".yellow())
+                        }
+                        (None, e) => println!(
+                            "Part {}: {}",
+                            part_num,
+                            format!("failed: {}", e.unwrap_or_default()).red()
+                        ),
+                    }
+                }
+            }
+            Err(e) => println!("{}", e.to_string().red()),
+        }
+        println!();
+    }
+
+    fn json(record: &DayRecord) -> serde_json::Value {
+        let part = |part_num| {
+            serde_json::json!({
+                "answer": record.raw(part_num),
+                "rendered": record.rendered(part_num),
+                "error": record.part_error(part_num),
+            })
+        };
+        let timings = record.times().map(|times| {
+            serde_json::json!({
+                "parse_ns": nanos(times.parse),
+                "part1_ns": nanos(times.part1),
+                "part2_ns": nanos(times.part2),
+                "total_ns": nanos(times.total()),
+            })
+        });
+        serde_json::json!({
+            "year": record.year,
+            "day": record.day,
+            "title": record.title,
+            "status": record.status(),
+            "error": record.error(),
+            "part1": part(1),
+            "part2": part(2),
+            "timings": timings,
+        })
+    }
+
+    fn print_csv(&mut self, record: &DayRecord) {
+        if !self.printed_header {
+            println!(
+                "year,day,title,status,part1,part2,part1_rendered,part2_rendered,\
+                 parse_ns,part1_ns,part2_ns,total_ns,error"
+            );
+            self.printed_header = true;
+        }
+        println!("{}", Self::csv_row(record));
+    }
+
+    fn csv_row(record: &DayRecord) -> String {
+        let times = record.times();
+        let time = |phase: fn(&PhaseTimes) -> Duration| {
+            times
+                .as_ref()
+                .map(|times| nanos(phase(times)).to_string())
+                .unwrap_or_default()
+        };
+        let error = record
+            .error()
+            .or_else(|| record.part_error(1))
+            .or_else(|| record.part_error(2));
+        let fields = [
+            record.year.to_string(),
+            record.day.to_string(),
+            record.title.to_string(),
+            record.status().to_string(),
+            record.raw(1).unwrap_or_default().to_string(),
+            record.raw(2).unwrap_or_default().to_string(),
+            record.rendered(1).unwrap_or_default(),
+            record.rendered(2).unwrap_or_default(),
+            time(|times| times.parse),
+            time(|times| times.part1),
+            time(|times| times.part2),
+            time(PhaseTimes::total),
+            error.unwrap_or_default(),
+        ];
+        let fields: Vec<String> = fields.iter().map(|field| csv_field(field)).collect();
+        fields.join(",")
+    }
+
+    fn print_markdown(&mut self, record: &DayRecord) {
+        if !self.printed_header {
+            println!("| Year | Day | Title | Part 1 | Part 2 | Time | Status |");
+            println!("| ---: | --: | ----- | ------ | ------ | ---: | ------ |");
+            self.printed_header = true;
+        }
+        println!("{}", Self::markdown_row(record));
+    }
+
+    fn markdown_row(record: &DayRecord) -> String {
+        let part = |part_num| match (record.raw(part_num), record.part_error(part_num)) {
+            (Some(answer), _) => format!("`{answer}`"),
+            _ if record.timed_out(part_num) => "TIMEOUT".to_string(),
+            (None, Some(e)) => format!("failed: {e}"),
+            (None, None) => String::new(),
+        };
+        let time = record
+            .times()
+            .map(|times| format!("{:.3?}", times.total()))
+            .unwrap_or_default();
+        let status = match record.error() {
+            Some(e) => format!("error: {e}"),
+            None => record.status().to_string(),
+        };
+        format!(
+            "| {} | {} | {} | {} | {} | {} | {} |",
+            record.year,
+            record.day,
+            markdown_cell(record.title),
+            markdown_cell(&part(1)),
+            markdown_cell(&part(2)),
+            time,
+            markdown_cell(&status)
+        )
+    }
+}
+
+/// Prints the banner of `year`, if the previous day printed was from another year.
+pub fn print_year(printed: &mut Option<usize>, year: usize) {
+    if *printed != Some(year) {
+        println!("{}", format!("Advent Of Code {year}").bold().blue());
+        println!();
+        *printed = Some(year);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use super::*;
+
+    /// A day whose first answer holds every character the formats must escape.
+    fn record() -> DayRecord {
+        DayRecord {
+            year: 2025,
+            day: 3,
+            title: "Lobby",
+            display: ("Got {answer}.", "Then {answer}."),
+            result: Ok(Solved {
+                part1: Ok("a,\"b|c\nd".to_string()),
+                part2: Err(SolveError::Overflow),
+                times: Some(PhaseTimes {
+                    parse: Duration::from_nanos(1),
+                    part1: Duration::from_nanos(20),
+                    part2: Duration::from_nanos(300),
+                }),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_csv_quotes_fields() {
+        assert_eq!(
+            Printer::csv_row(&record()),
+            "2025,3,Lobby,failed,\"a,\"\"b|c\nd\",,\"Got a,\"\"b|c\nd.\",,\
+             1,20,300,321,arithmetic overflow"
+        );
+    }
+
+    #[test]
+    fn test_markdown_escapes_cells() {
+        assert_eq!(
+            Printer::markdown_row(&record()),
+            "| 2025 | 3 | Lobby | `a,\"b\\|c<br>d` | failed: arithmetic overflow | 321.000ns | failed |"
+        );
+    }
+
+    #[test]
+    fn test_json_record() {
+        assert_eq!(
+            Printer::json(&record()),
+            serde_json::json!({
+                "year": 2025,
+                "day": 3,
+                "title": "Lobby",
+                "status": "failed",
+                "error": null,
+                "part1": {
+                    "answer": "a,\"b|c\nd",
+                    "rendered": "Got a,\"b|c\nd.",
+                    "error": null,
+                },
+                "part2": {
+                    "answer": null,
+                    "rendered": null,
+                    "error": "arithmetic overflow",
+                },
+                "timings": {
+                    "parse_ns": 1,
+                    "part1_ns": 20,
+                    "part2_ns": 300,
+                    "total_ns": 321,
+                },
+            })
+        );
+    }
+}
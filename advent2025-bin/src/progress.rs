@@ -0,0 +1,55 @@
+//! Progress bars for what solvers report through their `Context`.
+
+use std::time::Duration;
+
+use advent2025_lib::progress::Reporter;
+use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
+
+fn spinner_style() -> ProgressStyle {
+    ProgressStyle::with_template("{spinner} {msg} {elapsed}").unwrap()
+}
+
+fn counted_style() -> ProgressStyle {
+    ProgressStyle::with_template("{msg} {wide_bar} {pos}/{len} {elapsed}").unwrap()
+}
+
+/// The bar of one running solver: a spinner, until it reports how much work it has to do.
+pub struct Bar {
+    bar: ProgressBar,
+    label: String,
+}
+
+impl Bar {
+    pub fn start(multi: &MultiProgress, label: String) -> Self {
+        let bar = multi.add(
+            ProgressBar::new_spinner()
+                .with_style(spinner_style())
+                .with_message(label.clone()),
+        );
+        bar.enable_steady_tick(Duration::from_millis(100));
+        Bar { bar, label }
+    }
+
+    pub fn finish(self, multi: &MultiProgress) {
+        self.bar.finish_and_clear();
+        multi.remove(&self.bar);
+    }
+}
+
+impl Reporter for Bar {
+    fn phase(&self, name: &str) {
+        if self.bar.length().is_some() {
+            self.bar.set_style(spinner_style());
+            self.bar.unset_length();
+        }
+        self.bar.set_message(format!("{}: {}", self.label, name));
+    }
+
+    fn progress(&self, done: u64, total: u64) {
+        if self.bar.length() != Some(total) {
+            self.bar.set_style(counted_style());
+            self.bar.set_length(total);
+        }
+        self.bar.set_position(done);
+    }
+}
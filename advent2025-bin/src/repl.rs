@@ -0,0 +1,242 @@
+//! `repl`: parsing a day's input once, then solving its parts, switching input, parameters or
+//! solver, and solving again, all without restarting.
+
+use std::io::{BufRead, Write};
+use std::path::PathBuf;
+use std::time::Instant;
+
+use advent2025_lib::input::PathInput;
+use advent2025_lib::panic::isolated;
+use advent2025_lib::{AnswerResult, Context, DEFAULT_VARIANT, DayTrait, Loaded, Part};
+use color_eyre::{Report, eyre::eyre};
+use colored::*;
+
+const HELP: &str = "\
+Commands:
+  1, 2, both         solve part 1, part 2 or both parts
+  show               print the parsed input
+  example [N]        switch to example N [default: 1]
+  input [PATH]       switch to the real input, or to the file at PATH
+  param KEY=VALUE    override a parameter
+  params             list the parameter overrides
+  reset              drop every parameter override
+  variant [NAME]     solve with the named solver, or list the solvers
+  reload             read and parse the input again
+  help               print this help
+  quit               leave, as does end of input";
+
+/// Where the parsed input was read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Source {
+    /// The example at this index of the day's examples.
+    Example(usize),
+    Input,
+    File(PathBuf),
+}
+
+/// What the REPL has loaded, and the settings to load and solve it with.
+struct Session<'a> {
+    year: usize,
+    day_num: usize,
+    day: &'a dyn DayTrait,
+    source: Source,
+    /// Overrides given with `param`, applied after those of an example.
+    params: Vec<(String, String)>,
+    variant: String,
+    loaded: Option<Box<dyn Loaded + 'a>>,
+}
+
+impl<'a> Session<'a> {
+    /// Reads and parses the input, printing how long parsing took or why it failed.
+    fn load(&mut self) {
+        self.loaded = None;
+        let (input, example_params) = match &self.source {
+            Source::Example(idx) => {
+                let example = &self.day.all_examples()[*idx];
+                (Ok(example.input.to_string()), example.params)
+            }
+            Source::Input => (self.day.input(), &[][..]),
+            Source::File(path) => (self.day.input_from(&PathInput(path.clone())), &[][..]),
+        };
+        let input = match input {
+            Ok(input) => input,
+            Err(e) => return println!("{}", e.to_string().red()),
+        };
+        let overrides = crate::overrides(example_params, &self.params);
+        let started = Instant::now();
+        match self.day.load(&input, &overrides) {
+            Ok(loaded) => {
+                println!(
+                    "Parsed {} in {:.3?}",
+                    self.describe_source(),
+                    started.elapsed()
+                );
+                self.loaded = Some(loaded);
+            }
+            Err(e) => println!("{}", e.to_string().red()),
+        }
+    }
+
+    fn describe_source(&self) -> String {
+        match &self.source {
+            Source::Example(idx) => format!("example {}", idx + 1),
+            Source::Input => "the real input".to_string(),
+            Source::File(path) => path.display().to_string(),
+        }
+    }
+
+    fn solve(&self, part: Part) {
+        let part_num = part as usize + 1;
+        let Some(loaded) = &self.loaded else {
+            return println!("{}", "Nothing is loaded".red());
+        };
+        let started = Instant::now();
+        let answer: Result<Option<AnswerResult>, Report> =
+            isolated(|| loaded.solve(part, &self.variant, &Context::none())).map_err(Report::from);
+        let elapsed = started.elapsed();
+        let rendered = match answer {
+            Ok(Some(Ok(answer))) => answer.normal(),
+            Ok(Some(Err(e))) => format!("failed: {e}").red(),
+            Ok(None) => format!("no solver variant {:?} for this part", self.variant).yellow(),
+            Err(e) => e.to_string().red(),
+        };
+        println!(
+            "Part {part_num}: {rendered} {}",
+            format!("({elapsed:.3?})").dimmed()
+        );
+    }
+
+    /// Runs one line of input, and returns whether to keep going.
+    fn run(&mut self, line: &str) -> Result<bool, Report> {
+        let (command, argument) = match line.split_once(char::is_whitespace) {
+            Some((command, argument)) => (command, Some(argument.trim())),
+            None => (line, None),
+        };
+        match (command, argument) {
+            ("", None) => {}
+            ("1" | "part1", None) => self.solve(Part::First),
+            ("2" | "part2", None) => self.solve(Part::Second),
+            ("both", None) => {
+                self.solve(Part::First);
+                self.solve(Part::Second);
+            }
+            ("show", None) => match &self.loaded {
+                Some(loaded) => print!("{}", loaded.parsed()),
+                None => println!("{}", "Nothing is loaded".red()),
+            },
+            ("example", argument) => {
+                let count = self.day.all_examples().len();
+                let number = match argument {
+                    Some(number) => number
+                        .parse::<usize>()
+                        .map_err(|_| eyre!("expected an example number, found `{number}`"))?,
+                    None => 1,
+                };
+                if !(1..=count).contains(&number) {
+                    return Err(eyre!("expected an example from 1 to {count}"));
+                }
+                self.source = Source::Example(number - 1);
+                self.load();
+            }
+            ("input", argument) => {
+                self.source = match argument {
+                    Some(path) => Source::File(path.into()),
+                    None => Source::Input,
+                };
+                self.load();
+            }
+            ("param", Some(param)) => {
+                let param = crate::parse_param(param).map_err(|e| eyre!(e))?;
+                self.params.retain(|(key, _)| *key != param.0);
+                self.params.push(param);
+                self.load();
+            }
+            ("params", None) => {
+                if self.params.is_empty() {
+                    println!("No parameter overrides");
+                }
+                for (key, value) in &self.params {
+                    println!("{key}={value}");
+                }
+            }
+            ("reset", None) => {
+                self.params.clear();
+                self.load();
+            }
+            ("variant", None) => {
+                for part in [Part::First, Part::Second] {
+                    println!(
+                        "Part {}: {}",
+                        part as usize + 1,
+                        self.day.variants(part).join(", ")
+                    );
+                }
+                println!("Solving with {:?}", self.variant);
+            }
+            ("variant", Some(name)) => {
+                let known = [Part::First, Part::Second]
+                    .iter()
+                    .any(|part| self.day.variants(*part).contains(&name));
+                if !known {
+                    return Err(eyre!("no solver variant named {name:?}"));
+                }
+                self.variant = name.to_string();
+            }
+            ("reload", None) => self.load(),
+            ("help", None) => println!("{HELP}"),
+            ("quit" | "exit", None) => return Ok(false),
+            _ => return Err(eyre!("unknown command `{line}`, try `help`")),
+        }
+        Ok(true)
+    }
+
+    fn prompt(&self) -> String {
+        format!(
+            "{} day {} ({})> ",
+            self.year,
+            self.day_num,
+            self.describe_source()
+        )
+    }
+}
+
+/// Loads `day` from its example or its real input, then reads commands from stdin until `quit`
+/// or end of input.
+pub fn repl(year: usize, day_num: usize, day: &dyn DayTrait, example: bool) -> Result<(), Report> {
+    let mut session = Session {
+        year,
+        day_num,
+        day,
+        source: if example {
+            Source::Example(0)
+        } else {
+            Source::Input
+        },
+        params: Vec::new(),
+        variant: DEFAULT_VARIANT.to_string(),
+        loaded: None,
+    };
+    println!(
+        "{}",
+        format!("{year} day {day_num}: {}", day.title())
+            .bold()
+            .blue()
+    );
+    println!("Type `help` for the commands.");
+    session.load();
+
+    let mut lines = std::io::stdin().lock().lines();
+    loop {
+        print!("{}", session.prompt().bold());
+        std::io::stdout().flush()?;
+        let Some(line) = lines.next() else {
+            println!();
+            return Ok(());
+        };
+        match session.run(line?.trim()) {
+            Ok(true) => {}
+            Ok(false) => return Ok(()),
+            Err(e) => println!("{}", e.to_string().red()),
+        }
+    }
+}
@@ -0,0 +1,139 @@
+//! `validate`: parsing every input and example file with its day's parser, without solving,
+//! and pointing out whitespace a download or an editor may have mangled.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::time::Instant;
+
+use advent2025_lib::panic::isolated;
+use advent2025_lib::scaffold;
+use advent2025_lib::validate::{self, Inspection};
+use advent2025_lib::{DayTrait, Year};
+use color_eyre::{Report, eyre::eyre};
+use colored::*;
+
+/// The files found and what became of them.
+#[derive(Debug, Default)]
+struct Tally {
+    files: usize,
+    failed: usize,
+    warned: usize,
+    /// Files of days with no solution, which cannot be parsed.
+    skipped: usize,
+}
+
+/// Parses the file with the day's parser, in the scope of the day, and returns whether it did.
+fn parse_file(day: &dyn DayTrait, text: &str, is_example: bool) -> bool {
+    // An example file parses with the parameters of the day's example it holds, if any.
+    let params = match day
+        .all_examples()
+        .iter()
+        .find(|example| example.input == text)
+    {
+        Some(example) => example.params,
+        None if is_example => day.examples().first_example().params,
+        None => &[],
+    };
+    let started = Instant::now();
+    let result = isolated(|| day.load(text, params).map(|_| ()));
+    let elapsed = started.elapsed();
+    let error = match result {
+        Ok(Ok(())) => {
+            println!(
+                "    {} {}",
+                "parsed".green(),
+                format!("in {elapsed:.3?}").dimmed()
+            );
+            return true;
+        }
+        Ok(Err(e)) => e.to_string(),
+        Err(panic) => panic.to_string(),
+    };
+    println!("    {}", "failed to parse".red().bold());
+    for line in error.lines() {
+        println!("    {}", line.red());
+    }
+    false
+}
+
+fn validate_file(
+    days: &BTreeMap<usize, Box<dyn DayTrait>>,
+    day_num: usize,
+    path: &Path,
+    root: &Path,
+    is_example: bool,
+    tally: &mut Tally,
+) {
+    tally.files += 1;
+    println!("  {}", path.strip_prefix(root).unwrap_or(path).display());
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) => {
+            tally.failed += 1;
+            return println!("    {}", format!("cannot read: {e}").red());
+        }
+    };
+    let Inspection { stats, warnings } = validate::inspect(&text);
+    println!("    {}", stats.to_string().dimmed());
+    for warning in &warnings {
+        println!("    {}", format!("warning: {warning}").yellow());
+    }
+    if !warnings.is_empty() {
+        tally.warned += 1;
+    }
+    match days.get(&day_num) {
+        Some(day) => {
+            if !parse_file(day.as_ref(), &text, is_example) {
+                tally.failed += 1;
+            }
+        }
+        None => {
+            tally.skipped += 1;
+            println!("    {}", "no solution for this day, not parsed".yellow());
+        }
+    }
+}
+
+/// Checks the example and input files of the given years, and of only `day` if given.
+///
+/// Fails if any file cannot be read or parsed, or with `strict`, if any has a warning.
+pub fn validate(
+    years: &BTreeMap<usize, Year>,
+    day: Option<usize>,
+    strict: bool,
+) -> Result<(), Report> {
+    let root = scaffold::workspace()?;
+    let mut tally = Tally::default();
+    for year in years.values() {
+        println!("{}", format!("Validating {}", year.year).bold().blue());
+        let mut files = Vec::new();
+        for (dir, is_example) in [("examples", true), ("inputs", false)] {
+            let dir = root.join(dir).join(year.year.to_string());
+            let found = validate::day_files(&dir).map_err(|e| eyre!("{} ({e})", dir.display()))?;
+            files.extend(found.into_iter().map(|(day, path)| (day, is_example, path)));
+        }
+        // Examples before inputs within each day.
+        files.sort_by_key(|(day, is_example, _)| (*day, !is_example));
+        for (day_num, is_example, path) in files {
+            if day.is_some_and(|day| day != day_num) {
+                continue;
+            }
+            validate_file(&year.days, day_num, &path, &root, is_example, &mut tally);
+        }
+        println!();
+    }
+
+    let summary = format!(
+        "{} files: {} failed, {} with warnings, {} skipped",
+        tally.files, tally.failed, tally.warned, tally.skipped
+    );
+    if tally.failed > 0 {
+        return Err(eyre!("{summary}"));
+    }
+    if strict && tally.warned > 0 {
+        return Err(eyre!("{summary}, and warnings are errors with --strict"));
+    }
+    println!("{}", summary.green().bold());
+    Ok(())
+}
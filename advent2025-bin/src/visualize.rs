@@ -0,0 +1,312 @@
+//! `--visualize`: recording the frames a day emits, then playing them in the terminal, or
+//! exporting them as an asciicast v2 recording or as a sequence of images.
+
+use std::fs;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use advent2025_lib::logging;
+use advent2025_lib::visualize::{Frame, Visualizer};
+use colored::*;
+
+/// Keeps every frame emitted, captioned with the day and part that emitted it.
+#[derive(Default)]
+pub struct Recorder(Mutex<Vec<Frame>>);
+
+impl Recorder {
+    pub fn into_frames(self) -> Vec<Frame> {
+        self.0.into_inner().unwrap()
+    }
+}
+
+impl Visualizer for Recorder {
+    fn frame(&self, mut frame: Frame) {
+        if let Some(scope) = logging::current() {
+            frame.caption = format!("{scope}: {}", frame.caption);
+        }
+        self.0.lock().unwrap().push(frame);
+    }
+}
+
+/// The colour of a cell, shared by the terminal and the images so that they look alike.
+fn colour(cell: char) -> [u8; 3] {
+    match cell {
+        ' ' => [0, 0, 0],
+        '.' => [60, 60, 60],
+        '@' => [230, 200, 90],
+        'x' => [230, 70, 50],
+        '|' => [250, 240, 120],
+        '^' => [240, 240, 240],
+        'S' | 'X' => [80, 200, 90],
+        '#' => [220, 50, 50],
+        '0' => [80, 140, 240],
+        _ => [200, 200, 200],
+    }
+}
+
+/// The frame as the terminal shows it, after clearing the screen.
+fn screen(frame: &Frame) -> String {
+    let mut screen = format!("\x1b[2J\x1b[H{}\r\n", frame.caption.bold());
+    for row in &frame.rows {
+        for cell in row.chars() {
+            let [r, g, b] = colour(cell);
+            screen += &cell.to_string().truecolor(r, g, b).to_string();
+        }
+        screen += "\r\n";
+    }
+    screen
+}
+
+/// Plays the frames in the terminal, at `fps` frames per second.
+pub fn play(frames: &[Frame], fps: f64) -> std::io::Result<()> {
+    let mut stdout = std::io::stdout();
+    for frame in frames {
+        stdout.write_all(screen(frame).as_bytes())?;
+        stdout.flush()?;
+        std::thread::sleep(Duration::from_secs_f64(1.0 / fps));
+    }
+    Ok(())
+}
+
+/// Writes the frames as an asciicast v2 recording, which plays at `fps` frames per second.
+pub fn write_cast(path: &Path, title: &str, frames: &[Frame], fps: f64) -> std::io::Result<()> {
+    let width = frames.iter().map(Frame::width).max().unwrap_or(0);
+    let height = frames.iter().map(Frame::height).max().unwrap_or(0);
+    let mut file = BufWriter::new(fs::File::create(path)?);
+    let header = serde_json::json!({
+        "version": 2,
+        "width": width.max(80),
+        "height": height + 1,
+        "title": title,
+    });
+    writeln!(file, "{header}")?;
+    for (idx, frame) in frames.iter().enumerate() {
+        let event = serde_json::json!([idx as f64 / fps, "o", screen(frame)]);
+        writeln!(file, "{event}")?;
+    }
+    file.flush()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Png,
+    Ppm,
+}
+
+impl ImageFormat {
+    pub const NAMES: [&str; 2] = ["png", "ppm"];
+
+    fn extension(self) -> &'static str {
+        match self {
+            ImageFormat::Png => "png",
+            ImageFormat::Ppm => "ppm",
+        }
+    }
+}
+
+impl FromStr for ImageFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "png" => Ok(ImageFormat::Png),
+            "ppm" => Ok(ImageFormat::Ppm),
+            _ => Err(format!("unknown image format `{s}`")),
+        }
+    }
+}
+
+/// The side of the square of pixels that each cell is drawn as.
+const CELL_PIXELS: usize = 4;
+
+/// An image of RGB pixels, row by row.
+struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+}
+
+impl Image {
+    /// Draws the frame on a black canvas of `width` by `height` cells, so that every image of a
+    /// sequence has the same size.
+    fn draw(frame: &Frame, width: usize, height: usize) -> Self {
+        let (pixel_width, pixel_height) = (width * CELL_PIXELS, height * CELL_PIXELS);
+        let mut pixels = vec![0; pixel_width * pixel_height * 3];
+        for (row, cells) in frame.rows.iter().enumerate() {
+            for (col, cell) in cells.chars().enumerate() {
+                let rgb = colour(cell);
+                for y in row * CELL_PIXELS..(row + 1) * CELL_PIXELS {
+                    for x in col * CELL_PIXELS..(col + 1) * CELL_PIXELS {
+                        let idx = (y * pixel_width + x) * 3;
+                        pixels[idx..idx + 3].copy_from_slice(&rgb);
+                    }
+                }
+            }
+        }
+        Image {
+            width: pixel_width,
+            height: pixel_height,
+            pixels,
+        }
+    }
+
+    fn ppm(&self) -> Vec<u8> {
+        let mut ppm = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        ppm.extend_from_slice(&self.pixels);
+        ppm
+    }
+
+    /// Encodes the image as a PNG, stored without compression.
+    fn png(&self) -> Vec<u8> {
+        let mut raw = Vec::with_capacity((self.width * 3 + 1) * self.height);
+        for row in self.pixels.chunks(self.width * 3) {
+            // No filter.
+            raw.push(0);
+            raw.extend_from_slice(row);
+        }
+        let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+        let mut header = Vec::new();
+        header.extend_from_slice(&(self.width as u32).to_be_bytes());
+        header.extend_from_slice(&(self.height as u32).to_be_bytes());
+        // 8 bits per channel, RGB, and the only compression, filtering and interlacing.
+        header.extend_from_slice(&[8, 2, 0, 0, 0]);
+        png_chunk(&mut png, b"IHDR", &header);
+        png_chunk(&mut png, b"IDAT", &zlib_stored(&raw));
+        png_chunk(&mut png, b"IEND", &[]);
+        png
+    }
+}
+
+fn png_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    let crc = crc32(&png[start..]);
+    png.extend_from_slice(&crc.to_be_bytes());
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in bytes {
+        crc ^= u32::from(*byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+/// Wraps `data` in a zlib stream of uncompressed deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut zlib = vec![0x78, 0x01];
+    let mut blocks = data.chunks(u16::MAX as usize).peekable();
+    if blocks.peek().is_none() {
+        zlib.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        let len = block.len() as u16;
+        zlib.push(u8::from(last));
+        zlib.extend_from_slice(&len.to_le_bytes());
+        zlib.extend_from_slice(&(!len).to_le_bytes());
+        zlib.extend_from_slice(block);
+    }
+    let (mut a, mut b) = (1u32, 0u32);
+    for byte in data {
+        a = (a + u32::from(*byte)) % 65521;
+        b = (b + a) % 65521;
+    }
+    zlib.extend_from_slice(&((b << 16) | a).to_be_bytes());
+    zlib
+}
+
+/// Writes each frame as `frame-NNNNN` images in `dir`, all the size of the largest frame.
+pub fn write_images(dir: &Path, frames: &[Frame], format: ImageFormat) -> std::io::Result<()> {
+    fs::create_dir_all(dir)?;
+    let width = frames.iter().map(Frame::width).max().unwrap_or(0);
+    let height = frames.iter().map(Frame::height).max().unwrap_or(0);
+    for (idx, frame) in frames.iter().enumerate() {
+        let image = Image::draw(frame, width, height);
+        let bytes = match format {
+            ImageFormat::Png => image.png(),
+            ImageFormat::Ppm => image.ppm(),
+        };
+        let path = dir.join(format!("frame-{:05}.{}", idx + 1, format.extension()));
+        fs::write(path, bytes)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use super::*;
+
+    /// Undoes [`zlib_stored`], checking every block header and the checksum on the way.
+    fn unzlib_stored(zlib: &[u8]) -> (Vec<u8>, usize) {
+        assert_eq!(zlib[..2], [0x78, 0x01]);
+        let (mut data, mut blocks, mut at) = (Vec::new(), 0, 2);
+        loop {
+            let last = zlib[at] == 1;
+            let len = u16::from_le_bytes([zlib[at + 1], zlib[at + 2]]);
+            assert_eq!(u16::from_le_bytes([zlib[at + 3], zlib[at + 4]]), !len);
+            at += 5;
+            data.extend_from_slice(&zlib[at..at + len as usize]);
+            at += len as usize;
+            blocks += 1;
+            if last {
+                break;
+            }
+        }
+        assert_eq!(zlib.len(), at + 4);
+        (data, blocks)
+    }
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+    }
+
+    #[test]
+    fn test_png() {
+        let image = Image {
+            width: 1,
+            height: 1,
+            pixels: vec![0xff, 0, 0],
+        };
+        #[rustfmt::skip]
+        let expected = [
+            0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a,
+            // IHDR
+            0x00, 0x00, 0x00, 0x0d, 0x49, 0x48, 0x44, 0x52,
+            0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x08, 0x02, 0x00, 0x00, 0x00,
+            0x90, 0x77, 0x53, 0xde,
+            // IDAT
+            0x00, 0x00, 0x00, 0x0f, 0x49, 0x44, 0x41, 0x54,
+            0x78, 0x01, 0x01, 0x04, 0x00, 0xfb, 0xff, 0x00, 0xff, 0x00, 0x00, 0x03, 0x01, 0x01, 0x00,
+            0x8d, 0x1d, 0xe5, 0x82,
+            // IEND
+            0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4e, 0x44, 0xae, 0x42, 0x60, 0x82,
+        ];
+        assert_eq!(image.png(), expected);
+    }
+
+    #[test]
+    fn test_zlib_stored() {
+        assert_eq!(unzlib_stored(&zlib_stored(&[])), (vec![], 1));
+        let data: Vec<u8> = (0..70_000).map(|i| (i % 251) as u8).collect();
+        let zlib = zlib_stored(&data);
+        assert_eq!(unzlib_stored(&zlib), (data, 2));
+        assert_eq!(zlib[zlib.len() - 4..], 0x4ee3_7ee9u32.to_be_bytes());
+    }
+}
@@ -0,0 +1,179 @@
+//! `watch`: solving a day again on its example and its input whenever either file changes.
+//!
+//! The solvers are the ones compiled into this binary, so only the files are watched; editing
+//! the source still needs a rebuild.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use advent2025_lib::bench::{TimedRun, timed};
+use advent2025_lib::panic::{Panic, isolated};
+use advent2025_lib::scaffold::Scaffold;
+use advent2025_lib::watch::Watched;
+use advent2025_lib::{AnswerResult, Context, DayTrait, Example, ParseError, Part};
+use color_eyre::{Report, eyre::eyre};
+use colored::*;
+
+/// One of the files the day is solved on.
+struct Source {
+    name: &'static str,
+    path: PathBuf,
+    is_example: bool,
+}
+
+/// The answers of the last successful run on each source, by source and part number.
+type Previous = HashMap<(&'static str, usize), AnswerResult>;
+
+/// Solves `day` on its example and input, then again every time either file changes, polling
+/// every `interval` until interrupted.
+pub fn watch(
+    year: usize,
+    day_num: usize,
+    day: &dyn DayTrait,
+    interval: Duration,
+) -> Result<(), Report> {
+    let scaffold = Scaffold::locate(year, day_num)?;
+    let sources = [
+        Source {
+            name: "Example",
+            path: scaffold.example,
+            is_example: true,
+        },
+        Source {
+            name: "Input",
+            path: scaffold.input,
+            is_example: false,
+        },
+    ];
+    let mut watched = Watched::new(sources.iter().map(|source| source.path.clone()));
+    println!(
+        "{}",
+        format!("Watching {year} day {day_num}: {}", day.title())
+            .bold()
+            .blue()
+    );
+    for source in &sources {
+        println!("  {}", source.path.display());
+    }
+    println!("Solving again whenever either file changes, until interrupted.");
+    println!();
+
+    let mut previous = Previous::new();
+    println!("{}", "Run 1".bold());
+    for source in &sources {
+        solve_source(day, source, &mut previous);
+    }
+    let mut run = 1;
+    loop {
+        std::thread::sleep(interval);
+        let changed: Vec<String> = watched
+            .changed()
+            .iter()
+            .map(|path| path.display().to_string())
+            .collect();
+        if changed.is_empty() {
+            continue;
+        }
+        run += 1;
+        println!(
+            "{}",
+            format!("Run {run}: {} changed", changed.join(" and ")).bold()
+        );
+        for source in &sources {
+            solve_source(day, source, &mut previous);
+        }
+    }
+}
+
+/// The example of the day that the file contains, to check the answers against.
+fn matching_example(day: &dyn DayTrait, input: &str) -> Option<&'static Example> {
+    day.all_examples()
+        .iter()
+        .find(|example| example.input == input)
+}
+
+fn read(path: &Path) -> Result<String, Report> {
+    fs::read_to_string(path).map_err(|e| eyre!("{} ({e})", path.display()))
+}
+
+/// Solves the day on one source and prints the answers against the previous run, or the error
+/// in a panel.
+fn solve_source(day: &dyn DayTrait, source: &Source, previous: &mut Previous) {
+    let mut example = None;
+    let result = read(&source.path).and_then(|input| {
+        example = matching_example(day, &input);
+        // An edited example keeps the parameters of the day's examples.
+        let params = match example {
+            Some(example) => example.params,
+            None if source.is_example => day.examples().first_example().params,
+            None => &[],
+        };
+        match isolated(|| timed(day, &input, params, &Context::none())) {
+            Ok(run) => Ok(run?),
+            Err(panic) => Err(panic.into()),
+        }
+    });
+    let run: TimedRun = match result {
+        Ok(run) => run,
+        Err(e) => {
+            let kind = if e.downcast_ref::<ParseError>().is_some() {
+                "Parse error"
+            } else if e.downcast_ref::<Panic>().is_some() {
+                "Panic"
+            } else {
+                "Cannot read"
+            };
+            print_panel(
+                &format!("{kind} in {}", source.path.display()),
+                &e.to_string(),
+            );
+            println!();
+            return;
+        }
+    };
+
+    println!(
+        "{} {}",
+        source.name.bold(),
+        format!("({:.3?})", run.times.total()).dimmed()
+    );
+    for (part, answer) in [(Part::First, run.part1), (Part::Second, run.part2)] {
+        let part_num = part as usize + 1;
+        let mut line = match &answer {
+            Ok(answer) => answer.normal(),
+            Err(e) => format!("failed: {e}").red(),
+        }
+        .to_string();
+        match example.and_then(|example| example.expected(part)) {
+            Some(expected) if answer.as_deref() == Ok(expected) => {
+                line += &format!(" {}", "(matches the example)".green());
+            }
+            Some(expected) => line += &format!(" {}", format!("(expected {expected})").red()),
+            None => {}
+        }
+        match previous.insert((source.name, part_num), answer.clone()) {
+            Some(before) if before == answer => line += &format!(" {}", "(unchanged)".dimmed()),
+            Some(before) => {
+                let before = match before {
+                    Ok(before) => before,
+                    Err(e) => format!("failed: {e}"),
+                };
+                line += &format!(" {}", format!("(was {before})").yellow());
+            }
+            None => {}
+        }
+        println!("Part {part_num}: {line}");
+    }
+    println!();
+}
+
+/// Prints `body` in a box, so that it stands out among the answers.
+fn print_panel(title: &str, body: &str) {
+    println!("{}", format!("╭─ {title}").red().bold());
+    for line in body.lines() {
+        println!("{} {line}", "│".red());
+    }
+    println!("{}", "╰─".red());
+}
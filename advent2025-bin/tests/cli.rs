@@ -0,0 +1,72 @@
+use std::process::{Command, Output};
+
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_advent2025-bin"))
+        .args(args)
+        .env("NO_COLOR", "1")
+        .output()
+        .unwrap()
+}
+
+/// The day's heading and first answer on the example of day 5.
+fn assert_solved_day5(output: Output) {
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        output.status.success(),
+        "{stdout}{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(stdout.contains("Advent Of Code 2025\n"), "{stdout}");
+    assert!(stdout.contains("Day 5\nPart 1: 3 "), "{stdout}");
+}
+
+#[test]
+fn test_year_and_day() {
+    assert_solved_day5(run(&["2025", "5", "--example"]));
+}
+
+#[test]
+fn test_lone_day_of_latest_year() {
+    assert_solved_day5(run(&["5", "--example"]));
+}
+
+#[test]
+fn test_unknown_day() {
+    let output = run(&["42", "--example"]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("no solution for 2025 day 42"), "{stderr}");
+}
+
+#[test]
+fn test_unknown_variant() {
+    let output = run(&["9", "--example", "--variant", "nope"]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("no solver variant named \"nope\", expected one of default, flood_fill"),
+        "{stderr}"
+    );
+}
+
+#[test]
+fn test_compare_variants_without_input() {
+    let output = run(&["9", "--input", "/nonexistent", "--compare-variants"]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("solver variants disagree or failed"),
+        "{stderr}"
+    );
+}
+
+#[test]
+fn test_input_needs_a_day_to_verify() {
+    let output = run(&["--verify", "--input", "../inputs/2025/day01.txt"]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("--verify needs a day to run with it"),
+        "{stderr}"
+    );
+}
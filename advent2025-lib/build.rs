@@ -0,0 +1,123 @@
+//! Generates the registry of days, so that a new day only needs its module declared.
+//!
+//! Every module declared in `src/yYYYY/mod.rs` that defines `pub const DAY` is registered in
+//! `get_years` under its year and the number in its `day` field.
+
+use std::fmt::Write as _;
+use std::path::Path;
+
+/// The day modules of one year, sorted by name.
+struct YearModules {
+    year: usize,
+    days: Vec<String>,
+}
+
+fn defines_day(path: &Path) -> bool {
+    std::fs::read_to_string(path)
+        .unwrap()
+        .lines()
+        .any(|line| line.starts_with("pub const DAY:"))
+}
+
+/// The modules declared in the year's `mod.rs` that define a day, after checking that every
+/// file defining one is declared, as it would otherwise silently go missing.
+fn day_modules(dir: &Path) -> Vec<String> {
+    let mod_rs = dir.join("mod.rs");
+    let declared: Vec<String> = std::fs::read_to_string(&mod_rs)
+        .unwrap_or_else(|e| panic!("cannot read {}: {e}", mod_rs.display()))
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("pub mod ")?.strip_suffix(';'))
+        .map(str::to_string)
+        .collect();
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        let module = path.file_stem().unwrap().to_str().unwrap();
+        if path.extension().is_some_and(|ext| ext == "rs")
+            && defines_day(&path)
+            && !declared.iter().any(|declared| declared == module)
+        {
+            panic!(
+                "{} defines a day, but `pub mod {module};` is missing from {}",
+                path.display(),
+                mod_rs.display()
+            );
+        }
+    }
+    let mut modules: Vec<String> = declared
+        .into_iter()
+        .filter(|module| defines_day(&dir.join(format!("{module}.rs"))))
+        .collect();
+    modules.sort();
+    modules
+}
+
+fn main() {
+    let src = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap()).join("src");
+    println!("cargo::rerun-if-changed={}", src.display());
+    let lib = std::fs::read_to_string(src.join("lib.rs")).unwrap();
+
+    let mut years: Vec<YearModules> = std::fs::read_dir(&src)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_dir())
+        .filter_map(|dir| {
+            let name = dir.file_name()?.to_str()?;
+            let year = name.strip_prefix('y')?.parse().ok()?;
+            assert!(
+                lib.lines().any(|line| line == format!("mod y{year};")),
+                "`mod y{year};` is missing from src/lib.rs"
+            );
+            let days = day_modules(&dir);
+            Some(YearModules { year, days })
+        })
+        .collect();
+    years.sort_by_key(|year| year.year);
+
+    // Checked in a const block, so that a clash names the modules at compile time.
+    let mut out = String::new();
+    writeln!(out, "const _: () = {{").unwrap();
+    for YearModules { year, days } in &years {
+        for (i, first) in days.iter().enumerate() {
+            writeln!(
+                out,
+                "    assert!(y{year}::{first}::DAY.year == {year}, \
+                 \"`y{year}::{first}` should claim the year {year}\");"
+            )
+            .unwrap();
+            for second in &days[i + 1..] {
+                writeln!(
+                    out,
+                    "    assert!(y{year}::{first}::DAY.day != y{year}::{second}::DAY.day, \
+                     \"`y{year}::{first}` and `y{year}::{second}` claim the same day number\");"
+                )
+                .unwrap();
+            }
+        }
+    }
+    writeln!(out, "}};").unwrap();
+
+    writeln!(
+        out,
+        "fn register_years(years: &mut BTreeMap<usize, Year>) {{"
+    )
+    .unwrap();
+    for YearModules { year, days } in &years {
+        writeln!(out, "    let mut days = Days::new();").unwrap();
+        for module in days {
+            writeln!(
+                out,
+                "    days.insert(y{year}::{module}::DAY.day, Box::new(y{year}::{module}::DAY));"
+            )
+            .unwrap();
+        }
+        writeln!(
+            out,
+            "    years.insert({year}, Year {{ year: {year}, days }});"
+        )
+        .unwrap();
+    }
+    writeln!(out, "}}").unwrap();
+
+    let dest = Path::new(&std::env::var("OUT_DIR").unwrap()).join("days.rs");
+    std::fs::write(dest, out).unwrap();
+}
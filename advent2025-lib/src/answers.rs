@@ -0,0 +1,242 @@
+//! Expected answers for the real inputs, kept out of the source in `answers.toml`:
+//!
+//! ```toml
+//! [2025.day01]
+//! part1 = "1034"
+//! part2 = "6166"
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::{DayTrait, Part};
+
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnswersError {
+    /// No answers file was found, with every location that was searched.
+    NotFound(Vec<String>),
+    Invalid(String),
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            AnswersError::NotFound(searched) => {
+                write!(f, "no answers file found, searched:")?;
+                for location in searched {
+                    write!(f, "\n  {location}")?;
+                }
+                Ok(())
+            }
+            AnswersError::Invalid(e) => write!(f, "invalid answers file: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+/// Expected answers, keyed by year, day and part.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers(BTreeMap<(usize, usize), [Option<String>; 2]>);
+
+impl Answers {
+    /// Loads `answers.toml` from the current directory or its parent.
+    pub fn load() -> Result<Self, AnswersError> {
+        let mut searched = Vec::new();
+        for path in [
+            PathBuf::from(ANSWERS_FILE),
+            Path::new("..").join(ANSWERS_FILE),
+        ] {
+            match fs::read_to_string(&path) {
+                Ok(s) => return Self::parse(&s),
+                Err(e) => searched.push(format!("{} ({e})", path.display())),
+            }
+        }
+        Err(AnswersError::NotFound(searched))
+    }
+
+    pub fn from_path(path: &Path) -> Result<Self, AnswersError> {
+        let s = fs::read_to_string(path)
+            .map_err(|e| AnswersError::NotFound(vec![format!("{} ({e})", path.display())]))?;
+        Self::parse(&s)
+    }
+
+    pub fn parse(s: &str) -> Result<Self, AnswersError> {
+        let table: toml::Table = s
+            .parse()
+            .map_err(|e: toml::de::Error| AnswersError::Invalid(e.to_string()))?;
+        let mut answers = BTreeMap::new();
+        for (year_key, days) in table {
+            let year: usize = year_key.parse().map_err(|_| {
+                AnswersError::Invalid(format!("expected a year, found `{year_key}`"))
+            })?;
+            let days = days
+                .as_table()
+                .ok_or_else(|| AnswersError::Invalid(format!("`{year_key}` should be a table")))?;
+            for (day_key, parts) in days {
+                let key = format!("{year_key}.{day_key}");
+                let day: usize = day_key
+                    .strip_prefix("day")
+                    .and_then(|day| day.parse().ok())
+                    .ok_or_else(|| {
+                        AnswersError::Invalid(format!("expected `dayNN`, found `{key}`"))
+                    })?;
+                answers.insert((year, day), parse_parts(&key, parts)?);
+            }
+        }
+        Ok(Answers(answers))
+    }
+
+    pub fn get(&self, year: usize, day: usize, part: Part) -> Option<&str> {
+        let idx = match part {
+            Part::First => 0,
+            Part::Second => 1,
+        };
+        self.0.get(&(year, day))?[idx].as_deref()
+    }
+}
+
+/// Parses the `part1` and `part2` answers of the day table `key`.
+fn parse_parts(key: &str, parts: &toml::Value) -> Result<[Option<String>; 2], AnswersError> {
+    let parts = parts
+        .as_table()
+        .ok_or_else(|| AnswersError::Invalid(format!("`{key}` should be a table")))?;
+    let mut expected = [None, None];
+    for (part, answer) in parts {
+        let idx = match part.as_str() {
+            "part1" => 0,
+            "part2" => 1,
+            _ => {
+                return Err(AnswersError::Invalid(format!(
+                    "expected `part1` or `part2` in `{key}`, found `{part}`"
+                )));
+            }
+        };
+        // Answers are compared as rendered strings, but small ones may be written bare.
+        expected[idx] = Some(match answer {
+            toml::Value::String(s) => s.clone(),
+            toml::Value::Integer(i) => i.to_string(),
+            other => {
+                return Err(AnswersError::Invalid(format!(
+                    "`{key}.{part}` should be a string, found {}",
+                    other.type_str()
+                )));
+            }
+        });
+    }
+    Ok(expected)
+}
+
+/// The outcome of checking one part against the expected answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+        actual: String,
+    },
+    /// There is no expected answer to compare with.
+    Missing {
+        actual: String,
+    },
+    /// The part could not be solved at all.
+    Error(String),
+}
+
+impl Verdict {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Verdict::Fail { .. } | Verdict::Error(_))
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail { expected, actual } => {
+                write!(f, "FAIL (expected {expected}, got {actual})")
+            }
+            Verdict::Missing { actual } => write!(f, "missing (got {actual})"),
+            Verdict::Error(e) => write!(f, "ERROR ({e})"),
+        }
+    }
+}
+
+fn judge(expected: Option<&str>, actual: String) -> Verdict {
+    match expected {
+        None => Verdict::Missing { actual },
+        Some(expected) if expected == actual => Verdict::Pass,
+        Some(expected) => Verdict::Fail {
+            expected: expected.to_string(),
+            actual,
+        },
+    }
+}
+
+/// Solves `day` of `year` on `input`, and compares both parts against `answers`.
+pub fn verify(
+    year: usize,
+    day_num: usize,
+    day: &dyn DayTrait,
+    input: &str,
+    answers: &Answers,
+) -> [Verdict; 2] {
+    match day.both(input, &[]) {
+        Err(e) => [Verdict::Error(e.to_string()), Verdict::Error(e.to_string())],
+        Ok(results) => {
+            [(Part::First, results.0), (Part::Second, results.1)].map(|(part, result)| match result
+            {
+                Ok(actual) => judge(answers.get(year, day_num, part), actual),
+                Err(e) => Verdict::Error(e.to_string()),
+            })
+        }
+    }
+}
+
+/// The expected answer for a real input test, read from the answers file.
+#[cfg(test)]
+pub(crate) fn expected(year: usize, day: usize, part: Part) -> String {
+    Answers::load()
+        .unwrap()
+        .get(year, day, part)
+        .unwrap_or_else(|| panic!("no expected answer for {year} day {day} {part:?}"))
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(
+            "[2025.day01]\npart1 = \"1034\"\n\n[2025.day05]\npart2 = 14\n\n[2024.day01]\npart1 = 1\n",
+        )
+        .unwrap();
+        assert_eq!(answers.get(2025, 1, Part::First), Some("1034"));
+        assert_eq!(answers.get(2025, 1, Part::Second), None);
+        assert_eq!(answers.get(2025, 5, Part::Second), Some("14"));
+        assert_eq!(answers.get(2025, 9, Part::First), None);
+        assert_eq!(answers.get(2024, 1, Part::First), Some("1"));
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_keys() {
+        assert!(Answers::parse("[day01]\npart1 = \"1\"\n").is_err());
+        assert!(Answers::parse("[2025.first]\npart1 = \"1\"\n").is_err());
+        assert!(Answers::parse("[2025.day01]\npart3 = \"1\"\n").is_err());
+    }
+
+    #[test]
+    fn test_judge() {
+        assert_eq!(judge(Some("3"), "3".to_string()), Verdict::Pass);
+        assert!(judge(Some("3"), "4".to_string()).is_failure());
+        assert!(!judge(None, "4".to_string()).is_failure());
+    }
+}
@@ -0,0 +1,307 @@
+//! Named bench results kept on disk, and the comparison of later runs against them.
+//!
+//! A baseline is the JSON written by [`BenchReport::to_json`], so any `--bench-json` output
+//! can be used as one. Timings are compared with Welch's t-test at the 5% level.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::bench::{BenchReport, Phase, PhaseTimes, Stats, phase_stats};
+
+/// Where baselines are saved by default, relative to the current directory.
+pub const BASELINE_DIR: &str = "target/baselines";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BaselineError {
+    NotFound(String),
+    Invalid(String),
+    Io(String),
+}
+
+impl Display for BaselineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            BaselineError::NotFound(location) => write!(f, "no baseline found at {location}"),
+            BaselineError::Invalid(e) => write!(f, "invalid baseline: {e}"),
+            BaselineError::Io(e) => write!(f, "could not save baseline: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for BaselineError {}
+
+/// The samples of an earlier bench run, keyed by year and day.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Baseline(BTreeMap<(usize, usize), Vec<PhaseTimes>>);
+
+impl Baseline {
+    pub fn path(dir: &Path, name: &str) -> PathBuf {
+        dir.join(format!("{name}.json"))
+    }
+
+    /// Saves `report` as the baseline `name` in `dir`, returning the file written.
+    pub fn save(report: &BenchReport, dir: &Path, name: &str) -> Result<PathBuf, BaselineError> {
+        let path = Self::path(dir, name);
+        let io_error = |e: std::io::Error| BaselineError::Io(format!("{} ({e})", path.display()));
+        fs::create_dir_all(dir).map_err(io_error)?;
+        fs::write(&path, format!("{:#}\n", report.to_json())).map_err(io_error)?;
+        Ok(path)
+    }
+
+    pub fn load(dir: &Path, name: &str) -> Result<Self, BaselineError> {
+        let path = Self::path(dir, name);
+        let s = fs::read_to_string(&path)
+            .map_err(|e| BaselineError::NotFound(format!("{} ({e})", path.display())))?;
+        Self::parse(&s)
+    }
+
+    pub fn parse(s: &str) -> Result<Self, BaselineError> {
+        let json: serde_json::Value =
+            serde_json::from_str(s).map_err(|e| BaselineError::Invalid(e.to_string()))?;
+        let days = json["days"]
+            .as_array()
+            .ok_or_else(|| BaselineError::Invalid("`days` should be an array".to_string()))?;
+        let mut baseline = BTreeMap::new();
+        for day in days {
+            let number = |key: &str| {
+                day[key]
+                    .as_u64()
+                    .map(|n| n as usize)
+                    .ok_or_else(|| BaselineError::Invalid(format!("`{key}` should be a number")))
+            };
+            let (year, day_num) = (number("year")?, number("day")?);
+            let phase = |phase: Phase| -> Result<Vec<Duration>, BaselineError> {
+                day["samples_ns"][phase.name()]
+                    .as_array()
+                    .and_then(|samples| {
+                        samples
+                            .iter()
+                            .map(|sample| sample.as_u64().map(Duration::from_nanos))
+                            .collect()
+                    })
+                    .ok_or_else(|| {
+                        BaselineError::Invalid(format!(
+                            "{year} day {day_num} should have `samples_ns.{}` in nanoseconds",
+                            phase.name()
+                        ))
+                    })
+            };
+            let samples = zip_phases(
+                phase(Phase::Parse)?,
+                phase(Phase::Part1)?,
+                phase(Phase::Part2)?,
+            );
+            baseline.insert((year, day_num), samples);
+        }
+        Ok(Baseline(baseline))
+    }
+
+    /// Compares every phase of every day in `report` that the baseline also has.
+    pub fn compare(&self, report: &BenchReport) -> Vec<Comparison> {
+        let mut comparisons = Vec::new();
+        for day in &report.days {
+            let Some(baseline) = self.0.get(&(day.year, day.day)) else {
+                continue;
+            };
+            for phase in Phase::ALL {
+                let before: Vec<Duration> = baseline.iter().map(|times| times.get(phase)).collect();
+                let after: Vec<Duration> =
+                    day.samples.iter().map(|times| times.get(phase)).collect();
+                let change = if significant(&before, &after) {
+                    let (before, after) = (mean(&before), mean(&after));
+                    if after > before {
+                        Change::Regressed
+                    } else {
+                        Change::Improved
+                    }
+                } else {
+                    Change::Unchanged
+                };
+                comparisons.push(Comparison {
+                    year: day.year,
+                    day: day.day,
+                    phase,
+                    before: phase_stats(baseline, phase),
+                    after: phase_stats(&day.samples, phase),
+                    change,
+                });
+            }
+        }
+        comparisons
+    }
+}
+
+fn zip_phases(parse: Vec<Duration>, part1: Vec<Duration>, part2: Vec<Duration>) -> Vec<PhaseTimes> {
+    parse
+        .into_iter()
+        .zip(part1)
+        .zip(part2)
+        .map(|((parse, part1), part2)| PhaseTimes {
+            parse,
+            part1,
+            part2,
+        })
+        .collect()
+}
+
+/// Whether a phase got slower or faster than the baseline, beyond noise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    Regressed,
+    Improved,
+    Unchanged,
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Change::Regressed => write!(f, "regressed"),
+            Change::Improved => write!(f, "improved"),
+            Change::Unchanged => write!(f, "no change"),
+        }
+    }
+}
+
+/// One phase of one day, before and after.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comparison {
+    pub year: usize,
+    pub day: usize,
+    pub phase: Phase,
+    pub before: Stats,
+    pub after: Stats,
+    pub change: Change,
+}
+
+impl Comparison {
+    /// The relative change of the mean, as a percentage of the baseline.
+    pub fn percent(&self) -> f64 {
+        let before = self.before.mean.as_secs_f64();
+        if before == 0.0 {
+            return 0.0;
+        }
+        (self.after.mean.as_secs_f64() - before) / before * 100.0
+    }
+
+    /// Whether this is a significant regression of more than `threshold` percent.
+    pub fn exceeds(&self, threshold: f64) -> bool {
+        self.change == Change::Regressed && self.percent() > threshold
+    }
+}
+
+fn mean(samples: &[Duration]) -> f64 {
+    samples.iter().map(Duration::as_secs_f64).sum::<f64>() / samples.len() as f64
+}
+
+/// The unbiased sample variance.
+fn variance(samples: &[Duration]) -> f64 {
+    let mean = mean(samples);
+    samples
+        .iter()
+        .map(|sample| (sample.as_secs_f64() - mean).powi(2))
+        .sum::<f64>()
+        / (samples.len() - 1) as f64
+}
+
+/// Whether the means of `a` and `b` differ at the 5% level, by Welch's t-test.
+fn significant(a: &[Duration], b: &[Duration]) -> bool {
+    if a.len() < 2 || b.len() < 2 {
+        return false;
+    }
+    let (n_a, n_b) = (a.len() as f64, b.len() as f64);
+    let (se_a, se_b) = (variance(a) / n_a, variance(b) / n_b);
+    let se = se_a + se_b;
+    if se == 0.0 {
+        return mean(a) != mean(b);
+    }
+    let t = (mean(a) - mean(b)) / se.sqrt();
+    let df = se.powi(2) / (se_a.powi(2) / (n_a - 1.0) + se_b.powi(2) / (n_b - 1.0));
+    t.abs() > t_critical(df)
+}
+
+/// The two-sided 5% critical value of Student's t distribution with `df` degrees of freedom,
+/// by the Cornish-Fisher expansion around the normal quantile.
+fn t_critical(df: f64) -> f64 {
+    let z: f64 = 1.959964;
+    z + (z.powi(3) + z) / (4.0 * df)
+        + (5.0 * z.powi(5) + 16.0 * z.powi(3) + 3.0 * z) / (96.0 * df.powi(2))
+        + (3.0 * z.powi(7) + 19.0 * z.powi(5) + 17.0 * z.powi(3) - 15.0 * z) / (384.0 * df.powi(3))
+}
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use super::*;
+    use crate::bench::{BenchConfig, DayBench};
+
+    fn micros(samples: &[u64]) -> Vec<Duration> {
+        samples.iter().copied().map(Duration::from_micros).collect()
+    }
+
+    #[test]
+    fn test_t_critical() {
+        assert!((t_critical(10.0) - 2.228).abs() < 0.001);
+        assert!((t_critical(30.0) - 2.042).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_significant() {
+        let noisy = micros(&[100, 104, 98, 101, 97, 103]);
+        assert!(!significant(&noisy, &micros(&[99, 103, 100, 96, 102, 101])));
+        assert!(significant(
+            &noisy,
+            &micros(&[150, 149, 153, 148, 151, 152])
+        ));
+        assert!(!significant(&noisy, &micros(&[150])));
+    }
+
+    #[test]
+    fn test_round_trip_and_compare() {
+        let report = |part2: &[u64]| BenchReport {
+            config: BenchConfig {
+                warmup: 0,
+                iterations: part2.len(),
+            },
+            days: vec![DayBench {
+                year: 2025,
+                day: 7,
+                samples: micros(part2)
+                    .into_iter()
+                    .map(|part2| PhaseTimes {
+                        part2,
+                        ..PhaseTimes::default()
+                    })
+                    .collect(),
+                answers: (Ok(String::new()), Ok(String::new())),
+            }],
+        };
+        let baseline =
+            Baseline::parse(&report(&[100, 104, 98, 101, 97]).to_json().to_string()).unwrap();
+        let comparisons = baseline.compare(&report(&[50, 52, 49, 51, 50]));
+        let part2 = comparisons
+            .iter()
+            .find(|comparison| comparison.phase == Phase::Part2)
+            .unwrap();
+        assert_eq!(part2.change, Change::Improved);
+        assert!((part2.percent() + 50.0).abs() < 1.0);
+        assert!(!part2.exceeds(5.0));
+
+        let comparisons = baseline.compare(&report(&[150, 152, 149, 151, 150]));
+        assert!(comparisons.iter().any(|comparison| comparison.exceeds(5.0)));
+        assert!(
+            !comparisons
+                .iter()
+                .any(|comparison| comparison.exceeds(60.0))
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_missing_samples() {
+        assert!(Baseline::parse(r#"{"days": [{"year": 2025, "day": 1}]}"#).is_err());
+        assert!(Baseline::parse("not json").is_err());
+    }
+}
@@ -0,0 +1,394 @@
+//! Repeated timing of parsing and each part, summarised per day and over every day.
+
+use std::time::{Duration, Instant};
+
+use crate::{AnswerResult, Context, DayTrait, ParamOverrides, ParseResult};
+
+/// How long parsing and each part took in one run.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PhaseTimes {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl PhaseTimes {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+
+    pub fn get(&self, phase: Phase) -> Duration {
+        match phase {
+            Phase::Parse => self.parse,
+            Phase::Part1 => self.part1,
+            Phase::Part2 => self.part2,
+            Phase::Total => self.total(),
+        }
+    }
+}
+
+impl std::ops::Add for PhaseTimes {
+    type Output = PhaseTimes;
+
+    fn add(self, other: PhaseTimes) -> PhaseTimes {
+        PhaseTimes {
+            parse: self.parse + other.parse,
+            part1: self.part1 + other.part1,
+            part2: self.part2 + other.part2,
+        }
+    }
+}
+
+/// One timed run, with the answers it gave.
+#[derive(Debug, Clone)]
+pub struct TimedRun {
+    pub times: PhaseTimes,
+    pub part1: AnswerResult,
+    pub part2: AnswerResult,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+    Total,
+}
+
+impl Phase {
+    pub const ALL: [Phase; 4] = [Phase::Parse, Phase::Part1, Phase::Part2, Phase::Total];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part1",
+            Phase::Part2 => "part2",
+            Phase::Total => "total",
+        }
+    }
+}
+
+/// Measures a run from outside, as told when each of its phases starts and stops.
+///
+/// [`Phase::Total`] is never started or stopped.
+pub trait Probe {
+    fn start(&mut self, phase: Phase);
+    fn stop(&mut self, phase: Phase);
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Timer {
+    started: Instant,
+    times: PhaseTimes,
+}
+
+impl Probe for Timer {
+    fn start(&mut self, _phase: Phase) {
+        self.started = Instant::now();
+    }
+
+    fn stop(&mut self, phase: Phase) {
+        let elapsed = self.started.elapsed();
+        match phase {
+            Phase::Parse => self.times.parse = elapsed,
+            Phase::Part1 => self.times.part1 = elapsed,
+            Phase::Part2 => self.times.part2 = elapsed,
+            Phase::Total => {}
+        }
+    }
+}
+
+/// Parses the input and solves both parts once, timing each step.
+pub fn timed(
+    day: &dyn DayTrait,
+    input: &str,
+    params: &ParamOverrides,
+    ctx: &Context,
+) -> ParseResult<TimedRun> {
+    let mut timer = Timer {
+        started: Instant::now(),
+        times: PhaseTimes::default(),
+    };
+    let (part1, part2) = day.probed(input, params, ctx, &mut timer)?;
+    Ok(TimedRun {
+        times: timer.times,
+        part1,
+        part2,
+    })
+}
+
+/// Summary of repeated timings of one phase.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    /// The population standard deviation.
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Self {
+        if samples.is_empty() {
+            return Stats::default();
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+        let mean = sorted.iter().sum::<Duration>() / sorted.len() as u32;
+        let variance = sorted
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / sorted.len() as f64;
+        Stats {
+            min: sorted[0],
+            median,
+            mean,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+
+    fn to_json(self) -> serde_json::Value {
+        serde_json::json!({
+            "min_ns": self.min.as_nanos() as u64,
+            "median_ns": self.median.as_nanos() as u64,
+            "mean_ns": self.mean.as_nanos() as u64,
+            "stddev_ns": self.stddev.as_nanos() as u64,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Untimed runs before the timed ones, to warm caches and lazy statics.
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig {
+            warmup: 3,
+            iterations: 20,
+        }
+    }
+}
+
+/// The timings of every iteration of one day.
+#[derive(Debug, Clone)]
+pub struct DayBench {
+    pub year: usize,
+    pub day: usize,
+    pub samples: Vec<PhaseTimes>,
+    /// The answers of the last run.
+    pub answers: (AnswerResult, AnswerResult),
+}
+
+impl DayBench {
+    /// Combines a run of `first` on the input of part 1 and a run of `second` on that of part 2,
+    /// keeping the part each was run for and both parses.
+    pub fn merge_parts(first: DayBench, second: DayBench) -> DayBench {
+        let samples = first
+            .samples
+            .iter()
+            .zip(&second.samples)
+            .map(|(first, second)| PhaseTimes {
+                parse: first.parse + second.parse,
+                part1: first.part1,
+                part2: second.part2,
+            })
+            .collect();
+        DayBench {
+            samples,
+            answers: (first.answers.0, second.answers.1),
+            ..first
+        }
+    }
+}
+
+/// Summarises one phase of `samples`.
+pub fn phase_stats(samples: &[PhaseTimes], phase: Phase) -> Stats {
+    let samples: Vec<Duration> = samples.iter().map(|times| times.get(phase)).collect();
+    Stats::new(&samples)
+}
+
+/// Every sample of the measured phases, which [`Phase::Total`] is the sum of.
+fn samples_json(samples: &[PhaseTimes]) -> serde_json::Value {
+    [Phase::Parse, Phase::Part1, Phase::Part2]
+        .into_iter()
+        .map(|phase| {
+            let nanos: Vec<u64> = samples
+                .iter()
+                .map(|times| times.get(phase).as_nanos() as u64)
+                .collect();
+            (phase.name().to_string(), nanos.into())
+        })
+        .collect::<serde_json::Map<_, _>>()
+        .into()
+}
+
+fn phases_json(samples: &[PhaseTimes]) -> serde_json::Value {
+    Phase::ALL
+        .into_iter()
+        .map(|phase| {
+            (
+                phase.name().to_string(),
+                phase_stats(samples, phase).to_json(),
+            )
+        })
+        .collect::<serde_json::Map<_, _>>()
+        .into()
+}
+
+/// Times `day` on `input`, calling `progress` after every run, warmups included.
+pub fn bench_day(
+    year: usize,
+    day_num: usize,
+    day: &dyn DayTrait,
+    input: &str,
+    params: &ParamOverrides,
+    config: &BenchConfig,
+    mut progress: impl FnMut(),
+) -> ParseResult<DayBench> {
+    for _ in 0..config.warmup {
+        timed(day, input, params, &Context::none())?;
+        progress();
+    }
+    let mut samples = Vec::with_capacity(config.iterations);
+    let mut answers = None;
+    for _ in 0..config.iterations {
+        let run = timed(day, input, params, &Context::none())?;
+        samples.push(run.times);
+        answers = Some((run.part1, run.part2));
+        progress();
+    }
+    Ok(DayBench {
+        year,
+        day: day_num,
+        samples,
+        answers: answers.unwrap_or((Ok(String::new()), Ok(String::new()))),
+    })
+}
+
+/// The benchmarks of several days, run with the same configuration.
+#[derive(Debug, Clone)]
+pub struct BenchReport {
+    pub config: BenchConfig,
+    pub days: Vec<DayBench>,
+}
+
+impl BenchReport {
+    /// Every iteration summed over the days, as if they had been run back to back.
+    pub fn total(&self) -> Vec<PhaseTimes> {
+        (0..self.config.iterations)
+            .map(|idx| {
+                self.days
+                    .iter()
+                    .filter_map(|day| day.samples.get(idx))
+                    .fold(PhaseTimes::default(), |sum, times| sum + *times)
+            })
+            .collect()
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        let days: Vec<serde_json::Value> = self
+            .days
+            .iter()
+            .map(|day| {
+                serde_json::json!({
+                    "year": day.year,
+                    "day": day.day,
+                    "phases": phases_json(&day.samples),
+                    "samples_ns": samples_json(&day.samples),
+                })
+            })
+            .collect();
+        serde_json::json!({
+            "warmup": self.config.warmup,
+            "iterations": self.config.iterations,
+            "days": days,
+            "total": phases_json(&self.total()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use super::*;
+
+    fn ms(ms: u64) -> Duration {
+        Duration::from_millis(ms)
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::new(&[ms(4), ms(2), ms(6), ms(4)]);
+        assert_eq!(stats.min, ms(2));
+        assert_eq!(stats.median, ms(4));
+        assert_eq!(stats.mean, ms(4));
+        assert_eq!(stats.stddev.as_micros(), 1414);
+        assert_eq!(Stats::new(&[ms(3), ms(1), ms(2)]).median, ms(2));
+    }
+
+    #[test]
+    fn test_report_total() {
+        let times = |ms_each| PhaseTimes {
+            parse: ms(ms_each),
+            part1: ms(ms_each),
+            part2: ms(ms_each),
+        };
+        let day = |day, samples| DayBench {
+            year: 2025,
+            day,
+            samples,
+            answers: (Ok(String::new()), Ok(String::new())),
+        };
+        let report = BenchReport {
+            config: BenchConfig {
+                warmup: 0,
+                iterations: 2,
+            },
+            days: vec![
+                day(1, vec![times(1), times(2)]),
+                day(2, vec![times(3), times(5)]),
+            ],
+        };
+        let total = report.total();
+        assert_eq!(total[0].total(), ms(12));
+        assert_eq!(total[1].parse, ms(7));
+        let json = report.to_json();
+        assert_eq!(json["days"][1]["phases"]["part1"]["median_ns"], 4_000_000);
+        assert_eq!(json["total"]["total"]["min_ns"], 12_000_000);
+        assert_eq!(
+            json["days"][0]["samples_ns"]["parse"],
+            serde_json::json!([1_000_000, 2_000_000])
+        );
+    }
+
+    #[test]
+    fn test_merge_parts() {
+        let run = |ms_each: u64, answers: (&str, &str)| DayBench {
+            year: 2025,
+            day: 7,
+            samples: vec![PhaseTimes {
+                parse: ms(ms_each),
+                part1: ms(ms_each * 2),
+                part2: ms(ms_each * 3),
+            }],
+            answers: (Ok(answers.0.to_string()), Ok(answers.1.to_string())),
+        };
+        let merged = DayBench::merge_parts(run(1, ("a1", "a2")), run(10, ("b1", "b2")));
+        assert_eq!(merged.samples[0].parse, ms(11));
+        assert_eq!(merged.samples[0].part1, ms(2));
+        assert_eq!(merged.samples[0].part2, ms(30));
+        assert_eq!(merged.answers, (Ok("a1".to_string()), Ok("b2".to_string())));
+    }
+}
@@ -0,0 +1,279 @@
+use std::char::ParseCharError;
+use std::fmt::Display;
+use std::num::ParseIntError;
+
+use strum::ParseError as StrumParseError;
+
+/// What went wrong while parsing, independent of where it happened.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    Empty,
+    Int(ParseIntError),
+    Char(ParseCharError),
+    Str(String),
+    Strum(StrumParseError),
+}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ParseErrorKind::Empty => write!(f, "unexpected end of input"),
+            ParseErrorKind::Int(e) => write!(f, "{e}"),
+            ParseErrorKind::Char(e) => write!(f, "{e}"),
+            ParseErrorKind::Str(s) => write!(f, "{s}"),
+            ParseErrorKind::Strum(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+/// A parse failure, optionally located in the input.
+///
+/// Line and column are 1-based. Errors are usually raised without a location by the
+/// innermost parser, and then enriched on the way out with [`ParseError::at_column`],
+/// [`ParseError::at_line`] and [`ParseError::for_day`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError(Box<Details>);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Details {
+    kind: ParseErrorKind,
+    day: Option<usize>,
+    line: Option<usize>,
+    column: Option<usize>,
+    /// Number of characters to underline, starting at `column`.
+    width: usize,
+    /// The source line containing the error.
+    snippet: Option<String>,
+    /// Description of the token that was expected instead.
+    expected: Option<String>,
+}
+
+impl ParseError {
+    pub fn new(kind: ParseErrorKind) -> Self {
+        Self(Box::new(Details {
+            kind,
+            day: None,
+            line: None,
+            column: None,
+            width: 1,
+            snippet: None,
+            expected: None,
+        }))
+    }
+
+    /// The input ended, or a section was missing.
+    pub fn empty() -> Self {
+        Self::new(ParseErrorKind::Empty)
+    }
+
+    pub fn invalid(message: impl Into<String>) -> Self {
+        Self::new(ParseErrorKind::Str(message.into()))
+    }
+
+    pub fn kind(&self) -> &ParseErrorKind {
+        &self.0.kind
+    }
+
+    pub fn day(&self) -> Option<usize> {
+        self.0.day
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.0.line
+    }
+
+    pub fn column(&self) -> Option<usize> {
+        self.0.column
+    }
+
+    pub fn snippet(&self) -> Option<&str> {
+        self.0.snippet.as_deref()
+    }
+
+    pub fn expected_token(&self) -> Option<&str> {
+        self.0.expected.as_deref()
+    }
+
+    pub fn expected(mut self, expected: impl Into<String>) -> Self {
+        self.0.expected = Some(expected.into());
+        self
+    }
+
+    /// Locates the error within its line, unless a column is already known.
+    pub fn at_column(mut self, column: usize, width: usize) -> Self {
+        if self.0.column.is_none() {
+            self.0.column = Some(column);
+            self.0.width = width.max(1);
+        }
+        self
+    }
+
+    /// Shifts an already known column, for errors raised while parsing a sub-slice of a line.
+    pub fn offset_column(mut self, offset: usize) -> Self {
+        if let Some(column) = self.0.column.as_mut() {
+            *column += offset;
+        }
+        self
+    }
+
+    /// Locates the error in the input, unless a line is already known.
+    pub fn at_line(mut self, line: usize, snippet: &str) -> Self {
+        if self.0.line.is_none() {
+            self.0.line = Some(line);
+            self.0.snippet = Some(snippet.to_string());
+        }
+        self
+    }
+
+    pub fn for_day(mut self, day: usize) -> Self {
+        self.0.day.get_or_insert(day);
+        self
+    }
+}
+
+impl From<ParseIntError> for ParseError {
+    fn from(value: ParseIntError) -> Self {
+        Self::new(ParseErrorKind::Int(value))
+    }
+}
+
+impl From<ParseCharError> for ParseError {
+    fn from(value: ParseCharError) -> Self {
+        Self::new(ParseErrorKind::Char(value))
+    }
+}
+
+impl From<StrumParseError> for ParseError {
+    fn from(value: StrumParseError) -> Self {
+        Self::new(ParseErrorKind::Strum(value))
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.0.day {
+            Some(day) => write!(f, "invalid input for day {day}: {}", self.0.kind)?,
+            None => write!(f, "invalid input: {}", self.0.kind)?,
+        }
+        let location = match (self.0.line, self.0.column) {
+            (Some(line), Some(column)) => Some(format!("line {line}, column {column}")),
+            (Some(line), None) => Some(format!("line {line}")),
+            (None, Some(column)) => Some(format!("column {column}")),
+            (None, None) => None,
+        };
+        let expected = self
+            .0
+            .expected
+            .as_ref()
+            .map(|expected| format!("expected {expected}"));
+        let (Some(line), Some(snippet)) = (self.0.line, &self.0.snippet) else {
+            if let Some(location) = location {
+                write!(f, "\n --> {location}")?;
+            }
+            if let Some(expected) = expected {
+                write!(f, "\n {expected}")?;
+            }
+            return Ok(());
+        };
+        let gutter = " ".repeat(line.to_string().len());
+        write!(f, "\n{gutter}--> {}", location.unwrap_or_default())?;
+        write!(f, "\n{gutter} |")?;
+        write!(f, "\n{line} | {snippet}")?;
+        match self.0.column {
+            Some(column) => {
+                let padding = " ".repeat(column.saturating_sub(1));
+                let carets = "^".repeat(self.0.width);
+                write!(f, "\n{gutter} | {padding}{carets}")?;
+                if let Some(expected) = expected {
+                    write!(f, " {expected}")?;
+                }
+            }
+            None => {
+                if let Some(expected) = expected {
+                    write!(f, "\n{gutter} = {expected}")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The inner error of a [`ParseErrorKind`] is not a `source`, as `Display` already includes its
+/// message, which error reports would otherwise repeat.
+impl std::error::Error for ParseError {}
+
+pub type ParseResult<D> = std::result::Result<D, ParseError>;
+
+/// A failure while solving a part from successfully parsed input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    /// An arithmetic operation overflowed.
+    Overflow,
+    /// The search finished without finding an answer.
+    NoSolution,
+    /// The parsed input breaks an assumption of the solver.
+    InvalidState(String),
+    /// The part has no solver yet.
+    Unsolved,
+    /// The solver was told to stop before it finished, such as by a timeout.
+    Cancelled,
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SolveError::Overflow => write!(f, "arithmetic overflow"),
+            SolveError::NoSolution => write!(f, "no solution found"),
+            SolveError::InvalidState(s) => write!(f, "invalid state: {s}"),
+            SolveError::Unsolved => write!(f, "not solved yet"),
+            SolveError::Cancelled => write!(f, "cancelled"),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error as _;
+
+    use test_log::test;
+
+    use super::*;
+
+    #[test]
+    fn test_display_caret() {
+        let error = ParseError::from("x8".parse::<usize>().unwrap_err())
+            .at_column(2, 2)
+            .expected("a number of steps")
+            .at_line(3, "Lx8")
+            .for_day(1);
+        assert_eq!(
+            error.to_string(),
+            "invalid input for day 1: invalid digit found in string\n \
+             --> line 3, column 2\n  |\n3 | Lx8\n  |  ^^ expected a number of steps"
+        );
+    }
+
+    #[test]
+    fn test_inner_location_wins() {
+        let error = ParseError::empty()
+            .at_column(4, 1)
+            .at_column(1, 1)
+            .at_line(2, "first")
+            .at_line(1, "second");
+        assert_eq!(error.column(), Some(4));
+        assert_eq!(error.line(), Some(2));
+        assert_eq!(error.snippet(), Some("first"));
+    }
+
+    #[test]
+    fn test_inner_message_shown_once() {
+        let error = ParseError::from("x".parse::<usize>().unwrap_err());
+        assert_eq!(
+            error.to_string(),
+            "invalid input: invalid digit found in string"
+        );
+        assert!(error.source().is_none());
+    }
+}
@@ -0,0 +1,108 @@
+//! Seeded random inputs of any size, to stress-test solvers well beyond the real inputs.
+//!
+//! A day with a [`Generator`] sets it on its [`Day`](crate::Day); the text it writes is in
+//! exactly the format the day's parser accepts, and shaped like the real input.
+
+use std::ops::RangeInclusive;
+
+/// A small pseudo-random number generator (SplitMix64), so that a seed gives the same input
+/// on every platform and with every version of this crate.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `range`, which must not be empty.
+    ///
+    /// The slight bias towards low numbers of a plain modulo doesn't matter for inputs.
+    pub fn range(&mut self, range: RangeInclusive<usize>) -> usize {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "empty range {start}..={end}");
+        let random = self.next_u64();
+        match ((end - start) as u64).checked_add(1) {
+            Some(span) => start + (random % span) as usize,
+            None => random as usize,
+        }
+    }
+
+    /// Whether an event of the given probability happened.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+}
+
+/// Writes random inputs for one day.
+#[derive(Debug, Clone, Copy)]
+pub struct Generator {
+    /// What the size of an input counts, such as `"rotations"`.
+    pub unit: &'static str,
+    pub write: fn(usize, &mut Rng) -> String,
+}
+
+impl Generator {
+    /// The input of `size` units that `seed` gives, the same on every run.
+    pub fn generate(&self, size: usize, seed: u64) -> String {
+        (self.write)(size, &mut Rng::new(seed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use super::*;
+    use crate::{get_years, validate};
+
+    #[test]
+    fn test_rng_is_seeded() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..100).map(|_| rng.range(3..=7)).collect::<Vec<_>>()
+        };
+        assert_eq!(numbers(1), numbers(1));
+        assert_ne!(numbers(1), numbers(2));
+        assert!(numbers(1).iter().all(|n| (3..=7).contains(n)));
+        assert!((3..=7).all(|n| numbers(1).contains(&n)));
+        assert_eq!(Rng::new(5).range(9..=9), 9);
+        Rng::new(5).range(0..=usize::MAX);
+    }
+
+    #[test]
+    fn test_generated_inputs_solve() {
+        for year in get_years().values() {
+            for (day_num, day) in &year.days {
+                let Some(generator) = day.generator() else {
+                    continue;
+                };
+                for seed in 0..3 {
+                    let input = generator.generate(12, seed);
+                    assert_eq!(input, generator.generate(12, seed));
+                    let warnings = validate::inspect(&input).warnings;
+                    assert!(warnings.is_empty(), "day {day_num}: {warnings:?}");
+                    let (part1, part2) = day
+                        .both(&input, &[])
+                        .unwrap_or_else(|e| panic!("day {day_num} seed {seed}:\n{e}\n{input}"));
+                    assert!(
+                        part1.is_ok(),
+                        "day {day_num} seed {seed}: {part1:?}\n{input}"
+                    );
+                    assert!(
+                        part2.is_ok(),
+                        "day {day_num} seed {seed}: {part2:?}\n{input}"
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,219 @@
+use std::fmt::Display;
+use std::fs;
+use std::io::Read;
+use std::path::PathBuf;
+
+/// Environment variable naming a directory of `YYYY/dayNN.txt` inputs.
+pub const INPUT_DIR_VAR: &str = "ADVENT_INPUT_DIR";
+
+/// Somewhere a day's puzzle input can be read from.
+pub trait InputSource: Send + Sync {
+    /// Reads the input for `day` of `year`, or returns every location that was searched, with
+    /// the reason it failed.
+    fn read(&self, year: usize, day: usize) -> Result<String, Vec<Searched>>;
+}
+
+/// A location searched for an input, and why no input was read from it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Searched {
+    pub location: String,
+    pub reason: String,
+}
+
+impl Searched {
+    pub fn new(location: impl Display, reason: impl Display) -> Self {
+        Self {
+            location: location.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+}
+
+impl Display for Searched {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{} ({})", self.location, self.reason)
+    }
+}
+
+fn file_name(year: usize, day: usize) -> PathBuf {
+    PathBuf::from(year.to_string()).join(format!("day{day:02}.txt"))
+}
+
+fn read_file(path: PathBuf) -> Result<String, Searched> {
+    fs::read_to_string(&path).map_err(|e| Searched::new(path.display(), e))
+}
+
+/// A single file, used whatever the day.
+pub struct PathInput(pub PathBuf);
+
+impl InputSource for PathInput {
+    fn read(&self, _year: usize, _day: usize) -> Result<String, Vec<Searched>> {
+        read_file(self.0.clone()).map_err(|searched| vec![searched])
+    }
+}
+
+/// A directory of `YYYY/dayNN.txt` files.
+pub struct DirInput(pub PathBuf);
+
+impl InputSource for DirInput {
+    fn read(&self, year: usize, day: usize) -> Result<String, Vec<Searched>> {
+        read_file(self.0.join(file_name(year, day))).map_err(|searched| vec![searched])
+    }
+}
+
+/// The directory named by [`INPUT_DIR_VAR`], looked up on every read.
+pub struct EnvDirInput;
+
+impl InputSource for EnvDirInput {
+    fn read(&self, year: usize, day: usize) -> Result<String, Vec<Searched>> {
+        match std::env::var_os(INPUT_DIR_VAR) {
+            Some(dir) => DirInput(dir.into()).read(year, day),
+            None => Err(vec![Searched::new(format!("${INPUT_DIR_VAR}"), "not set")]),
+        }
+    }
+}
+
+/// Everything on standard input.
+pub struct StdinInput;
+
+impl InputSource for StdinInput {
+    fn read(&self, _year: usize, _day: usize) -> Result<String, Vec<Searched>> {
+        let mut input = String::new();
+        std::io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|e| vec![Searched::new("<stdin>", e)])?;
+        Ok(input)
+    }
+}
+
+/// An alternate input `YYYY/dayNN-<name>.txt`, searched for in each of `dirs`.
+pub struct NamedInput {
+    pub name: String,
+    pub dirs: Vec<PathBuf>,
+}
+
+impl InputSource for NamedInput {
+    fn read(&self, year: usize, day: usize) -> Result<String, Vec<Searched>> {
+        let file_name =
+            PathBuf::from(year.to_string()).join(format!("day{day:02}-{}.txt", self.name));
+        let mut searched = Vec::new();
+        for dir in &self.dirs {
+            match read_file(dir.join(&file_name)) {
+                Ok(input) => return Ok(input),
+                Err(location) => searched.push(location),
+            }
+        }
+        Err(searched)
+    }
+}
+
+/// An input compiled into the binary, used whatever the day.
+pub struct EmbeddedInput(pub &'static str);
+
+impl InputSource for EmbeddedInput {
+    fn read(&self, _year: usize, _day: usize) -> Result<String, Vec<Searched>> {
+        Ok(self.0.to_string())
+    }
+}
+
+/// Tries each source in turn, returning the first input found.
+pub struct ChainInput(pub Vec<Box<dyn InputSource>>);
+
+impl ChainInput {
+    /// The default search: [`INPUT_DIR_VAR`], then `inputs/` and `../inputs/`.
+    pub fn standard() -> Self {
+        ChainInput(vec![
+            Box::new(EnvDirInput),
+            Box::new(DirInput("inputs".into())),
+            Box::new(DirInput("../inputs".into())),
+        ])
+    }
+}
+
+impl InputSource for ChainInput {
+    fn read(&self, year: usize, day: usize) -> Result<String, Vec<Searched>> {
+        let mut searched = Vec::new();
+        for source in &self.0 {
+            match source.read(year, day) {
+                Ok(input) => return Ok(input),
+                Err(locations) => searched.extend(locations),
+            }
+        }
+        Err(searched)
+    }
+}
+
+/// No input could be read for a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputError {
+    pub year: usize,
+    pub day: usize,
+    /// Every location that was searched, with the reason it failed.
+    pub searched: Vec<Searched>,
+}
+
+impl InputError {
+    pub fn new(year: usize, day: usize, searched: Vec<Searched>) -> Self {
+        Self {
+            year,
+            day,
+            searched,
+        }
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "no input found for {} day {}, searched:",
+            self.year, self.day
+        )?;
+        for location in &self.searched {
+            write!(f, "\n  {location}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for InputError {}
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use super::*;
+
+    #[test]
+    fn test_chain_lists_searched_locations() {
+        let source = ChainInput(vec![
+            Box::new(DirInput("missing".into())),
+            Box::new(NamedInput {
+                name: "alt".to_string(),
+                dirs: vec!["also-missing".into()],
+            }),
+        ]);
+        let error = InputError::new(2025, 5, source.read(2025, 5).unwrap_err());
+        assert_eq!(error.searched.len(), 2);
+        assert_eq!(error.searched[0].location, "missing/2025/day05.txt");
+        assert_eq!(
+            error.searched[1].location,
+            "also-missing/2025/day05-alt.txt"
+        );
+        assert!(
+            error.to_string().starts_with(
+                "no input found for 2025 day 5, searched:\n  missing/2025/day05.txt ("
+            )
+        );
+    }
+
+    #[test]
+    fn test_chain_returns_first_found() {
+        let source = ChainInput(vec![
+            Box::new(DirInput("missing".into())),
+            Box::new(EmbeddedInput("embedded")),
+            Box::new(DirInput("../inputs".into())),
+        ]);
+        assert_eq!(source.read(2025, 1).unwrap(), "embedded");
+    }
+}
@@ -1,28 +1,33 @@
-use std::char::ParseCharError;
 use std::collections::btree_map::BTreeMap;
-use std::fmt::Display;
-use std::fs;
-use std::num::ParseIntError;
 use std::rc::Rc;
 
-use strum::ParseError as StrumParseError;
-
+pub mod answers;
+pub mod baseline;
+pub mod bench;
+mod error;
+pub mod generate;
+pub mod input;
+pub mod logging;
+pub mod panic;
 pub mod parser;
-// mod test;
-
-mod day00_template;
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-// mod day10;
-// mod day11;
-// mod day12;
+pub mod progress;
+pub mod scaffold;
+pub mod scheduler;
+mod test;
+pub mod validate;
+pub mod visualize;
+pub mod watch;
+mod y2025;
+
+// The registry of every day module that defines `pub const DAY`, generated by `build.rs`.
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+use bench::{Phase, Probe};
+pub use error::{ParseError, ParseErrorKind, ParseResult, SolveError};
+use generate::Generator;
+pub use input::{ChainInput, InputError, InputSource, Searched};
+use logging::Scope;
+pub use progress::Context;
 
 #[derive(Debug, Clone, Copy)]
 pub enum Part {
@@ -30,107 +35,151 @@ pub enum Part {
     Second,
 }
 
-#[derive(Debug)]
-pub enum ParseError {
-    Empty,
-    Int(ParseIntError),
-    Char(ParseCharError),
-    Str(String),
-    Strum(StrumParseError),
+#[derive(Debug, Default)]
+pub struct PartOutput<O> {
+    answer: O,
 }
 
-impl From<ParseIntError> for ParseError {
-    fn from(value: ParseIntError) -> Self {
-        Self::Int(value)
+impl<O: Clone> PartOutput<O> {
+    pub fn unwrap(&self) -> O {
+        self.answer.clone()
     }
 }
 
-impl From<ParseCharError> for ParseError {
-    fn from(value: ParseCharError) -> Self {
-        Self::Char(value)
-    }
+pub type SolveResult<O> = std::result::Result<PartOutput<O>, SolveError>;
+
+/// Constants of a puzzle that differ between the examples and the real input.
+///
+/// [`Default`] gives the values for the real input, which examples and the command line
+/// override by name.
+pub trait Params: Default {
+    /// Overrides the parameter named `key` with `value`.
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String>;
 }
 
-impl From<StrumParseError> for ParseError {
-    fn from(value: StrumParseError) -> Self {
-        Self::Strum(value)
+impl Params for () {
+    fn set(&mut self, key: &str, _value: &str) -> Result<(), String> {
+        Err(format!("unknown parameter `{key}`"))
     }
 }
 
-impl Display for ParseError {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "invalid input for day")
-    }
+/// Parameter overrides as `(key, value)` pairs, applied in order.
+pub type ParamOverrides<'a> = [(&'a str, &'a str)];
+
+pub struct DayCalc<D, O1, O2, P = ()> {
+    pub parse: fn(&str) -> ParseResult<D>,
+    pub part1: fn(&D, &P, &Context) -> SolveResult<O1>,
+    pub part2: fn(&D, &P, &Context) -> SolveResult<O2>,
 }
 
-pub type ParseResult<D> = std::result::Result<D, ParseError>;
+/// Name of the solver in [`DayCalc`], as opposed to an alternative [`Variant`].
+pub const DEFAULT_VARIANT: &str = "default";
 
-#[derive(Debug, Default)]
-pub struct PartOutput<O> {
-    answer: O,
+/// An alternative solver for one part, kept alive to cross-check the default one.
+pub struct Variant<D, O, P = ()> {
+    pub name: &'static str,
+    pub solve: fn(&D, &P, &Context) -> SolveResult<O>,
 }
 
-impl<O: Clone> PartOutput<O> {
-    pub fn unwrap(&self) -> O {
-        self.answer.clone()
-    }
+pub struct Variants<D: 'static, O1: 'static, O2: 'static, P: 'static = ()> {
+    pub part1: &'static [Variant<D, O1, P>],
+    pub part2: &'static [Variant<D, O2, P>],
 }
 
-pub struct DayCalc<D, O> {
-    pub parse: fn(&str) -> ParseResult<D>,
-    pub part1: fn(&D) -> PartOutput<O>,
-    pub part2: fn(&D) -> PartOutput<O>,
+impl<D, O1, O2, P> Variants<D, O1, O2, P> {
+    pub const fn none() -> Self {
+        Variants {
+            part1: &[],
+            part2: &[],
+        }
+    }
 }
 
-pub struct Examples<const C: usize, const F: usize, const S: usize> {
-    pub common: [&'static str; C],
-    pub part1: [&'static str; F],
-    pub part2: [&'static str; S],
+/// An example input from the puzzle text, with the answers it gives.
+///
+/// Answers are compared once rendered, and are `None` for a part the example doesn't cover.
+pub struct Example {
+    pub input: &'static str,
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+    /// Overrides of the day's [`Params`] for this example.
+    pub params: &'static ParamOverrides<'static>,
 }
-impl Examples<1, 0, 0> {
-    const fn single(include_str: &'static str) -> Self {
-        Examples {
-            common: [include_str],
-            part1: [],
-            part2: [],
+
+impl Example {
+    pub const fn both(input: &'static str, part1: &'static str, part2: &'static str) -> Self {
+        Example {
+            input,
+            part1: Some(part1),
+            part2: Some(part2),
+            params: &[],
         }
     }
-}
-impl Examples<0, 1, 1> {
-    #[allow(dead_code)]
-    const fn pair(first: &'static str, second: &'static str) -> Self {
-        Examples {
-            common: [],
-            part1: [first],
-            part2: [second],
+
+    pub const fn part1(input: &'static str, answer: &'static str) -> Self {
+        Example {
+            input,
+            part1: Some(answer),
+            part2: None,
+            params: &[],
+        }
+    }
+
+    pub const fn part2(input: &'static str, answer: &'static str) -> Self {
+        Example {
+            input,
+            part1: None,
+            part2: Some(answer),
+            params: &[],
+        }
+    }
+
+    pub const fn with_params(self, params: &'static ParamOverrides<'static>) -> Self {
+        Example { params, ..self }
+    }
+
+    pub fn expected(&self, part: Part) -> Option<&'static str> {
+        match part {
+            Part::First => self.part1,
+            Part::Second => self.part2,
         }
     }
 }
 
-pub struct Day<D, O, const C: usize, const F: usize, const S: usize> {
+pub struct Day<D: 'static, O1: 'static, O2: 'static, P: 'static = ()> {
+    pub year: usize,
     pub day: usize,
     pub title: &'static str,
     pub display: (&'static str, &'static str),
-    pub calc: DayCalc<D, O>,
-    pub examples: Examples<C, F, S>,
+    pub calc: DayCalc<D, O1, O2, P>,
+    pub variants: Variants<D, O1, O2, P>,
+    /// Writes random inputs of any size, for days that have a generator.
+    pub generator: Option<Generator>,
+    pub examples: &'static [Example],
 }
 
 pub enum PrimaryExample {
-    Same(&'static str),
-    Different([&'static str; 2]),
+    Same(&'static Example),
+    Different([&'static Example; 2]),
 }
 
 impl PrimaryExample {
     pub fn first(&self) -> &'static str {
+        self.first_example().input
+    }
+    pub fn second(&self) -> &'static str {
+        self.second_example().input
+    }
+    pub fn first_example(&self) -> &'static Example {
         match self {
-            PrimaryExample::Same(s) => s,
-            PrimaryExample::Different(s) => s[0],
+            PrimaryExample::Same(example) => example,
+            PrimaryExample::Different(examples) => examples[0],
         }
     }
-    pub fn second(&self) -> &'static str {
+    pub fn second_example(&self) -> &'static Example {
         match self {
-            PrimaryExample::Same(s) => s,
-            PrimaryExample::Different(s) => s[1],
+            PrimaryExample::Same(example) => example,
+            PrimaryExample::Different(examples) => examples[1],
         }
     }
 }
@@ -139,113 +188,357 @@ pub trait Printable {
     fn display(&self) -> (&'static str, &'static str);
     fn title(&self) -> &'static str;
     fn examples(&self) -> PrimaryExample;
+    fn all_examples(&self) -> &'static [Example];
 }
 
-impl<D, O, const C: usize, const F: usize, const S: usize> Printable for Day<D, O, C, F, S> {
+impl<D: 'static, O1: 'static, O2: 'static, P: 'static> Printable for Day<D, O1, O2, P> {
     fn display(&self) -> (&'static str, &'static str) {
         self.display
     }
     fn title(&self) -> &'static str {
         self.title
     }
+    /// The first example with an answer for each part, or else the first example.
     fn examples(&self) -> PrimaryExample {
-        let first = self
-            .examples
-            .part1
-            .first()
-            .or(self.examples.common.first())
-            .unwrap();
-        let second = self
-            .examples
-            .part2
-            .first()
-            .or(self.examples.common.first())
-            .unwrap();
-        if first == second {
+        let primary = |part| {
+            self.examples
+                .iter()
+                .find(|example| example.expected(part).is_some())
+                .or(self.examples.first())
+                .unwrap()
+        };
+        let (first, second) = (primary(Part::First), primary(Part::Second));
+        if std::ptr::eq(first, second) {
             PrimaryExample::Same(first)
         } else {
             PrimaryExample::Different([first, second])
         }
     }
+    fn all_examples(&self) -> &'static [Example] {
+        self.examples
+    }
 }
 
-type DayResult = ParseResult<(String, String)>;
+/// The rendered answer of a single part.
+pub type AnswerResult = std::result::Result<String, SolveError>;
+
+type DayResult = ParseResult<(AnswerResult, AnswerResult)>;
+
+type DayFunc = Rc<dyn Fn(&str, &ParamOverrides) -> DayResult>;
+
+/// The answer of every solver for one part, starting with [`DEFAULT_VARIANT`].
+pub type VariantAnswers = Vec<(&'static str, AnswerResult)>;
+
+/// A day's input, parsed once along with its parameters, to solve the parts of as often as
+/// needed.
+pub trait Loaded {
+    /// The parsed input, through its `Display` impl.
+    fn parsed(&self) -> String;
+    /// Solves `part` with the named solver, or returns `None` if the day has no such variant.
+    fn solve(&self, part: Part, variant: &str, ctx: &Context) -> Option<AnswerResult>;
+}
 
 pub trait Calculable {
-    fn calc(&self, part: Part, input: &str) -> ParseResult<String>;
-    fn both(&self, input: &str) -> DayResult;
-    fn both_func(&self) -> Rc<dyn Fn(&str) -> DayResult>;
+    fn calc(&self, part: Part, input: &str, params: &ParamOverrides) -> ParseResult<AnswerResult>;
+    fn both(&self, input: &str, params: &ParamOverrides) -> DayResult;
+    fn both_func(&self) -> DayFunc;
+    /// Names of the solvers for `part`, starting with [`DEFAULT_VARIANT`].
+    fn variants(&self, part: Part) -> Vec<&'static str>;
+    /// Solves `part` with the named solver, reporting through `ctx`, or returns `None` if the
+    /// day has no such variant.
+    fn calc_variant(
+        &self,
+        part: Part,
+        variant: &str,
+        input: &str,
+        params: &ParamOverrides,
+        ctx: &Context,
+    ) -> Option<ParseResult<AnswerResult>>;
+    /// Parses the input and applies the parameters, for the parts to be solved later.
+    fn load(&self, input: &str, params: &ParamOverrides) -> ParseResult<Box<dyn Loaded + '_>>;
+    /// Parses the input once and solves both parts with every solver.
+    fn all_variants(
+        &self,
+        input: &str,
+        params: &ParamOverrides,
+    ) -> ParseResult<(VariantAnswers, VariantAnswers)>;
+    /// Parses the input and solves both parts, telling `probe` as each phase starts and stops.
+    fn probed(
+        &self,
+        input: &str,
+        params: &ParamOverrides,
+        ctx: &Context,
+        probe: &mut dyn Probe,
+    ) -> DayResult;
 }
 
-impl<D: 'static, O: 'static + std::fmt::Display, const C: usize, const F: usize, const S: usize>
-    Calculable for Day<D, O, C, F, S>
+fn render<O: std::fmt::Display>(output: SolveResult<O>) -> AnswerResult {
+    output.map(|output| output.answer.to_string())
+}
+
+/// Applies `overrides` over the default parameters, reporting bad ones as invalid input.
+fn apply_params<P: Params>(day: usize, overrides: &ParamOverrides) -> ParseResult<P> {
+    let mut params = P::default();
+    for (key, value) in overrides {
+        params.set(key, value).map_err(|e| {
+            ParseError::invalid(format!("parameter `{key}={value}`: {e}")).for_day(day)
+        })?;
+    }
+    Ok(params)
+}
+
+impl<D, O1, O2, P> Day<D, O1, O2, P> {
+    /// Calls `f` in the [`logging`] scope of `phase` of this day.
+    fn scoped<T>(&self, phase: Phase, f: impl FnOnce() -> T) -> T {
+        let scope = Scope {
+            year: self.year,
+            day: self.day,
+            phase,
+        };
+        logging::scoped(scope, f)
+    }
+}
+
+impl<D, O1, O2, P> Day<D, O1, O2, P>
+where
+    P: Params,
 {
-    fn calc(&self, part: Part, input: &str) -> ParseResult<String> {
-        let parse = self.calc.parse;
-        let input = parse(input)?;
-        Ok(match part {
-            Part::First => (self.calc.part1)(&input).answer.to_string(),
-            Part::Second => (self.calc.part2)(&input).answer.to_string(),
-        })
+    fn prepare(&self, input: &str, overrides: &ParamOverrides) -> ParseResult<(D, P)> {
+        let params = apply_params(self.day, overrides)?;
+        let input = self
+            .scoped(Phase::Parse, || (self.calc.parse)(input))
+            .map_err(|e| e.for_day(self.day))?;
+        Ok((input, params))
     }
-    fn both(&self, input: &str) -> DayResult {
-        let parse = self.calc.parse;
-        let part1 = self.calc.part1;
-        let part2 = self.calc.part2;
-        let input = parse(input)?;
-        Ok((
-            part1(&input).answer.to_string(),
-            part2(&input).answer.to_string(),
-        ))
+}
+
+impl<D, O1, O2, P> Day<D, O1, O2, P>
+where
+    O1: std::fmt::Display,
+    O2: std::fmt::Display,
+{
+    /// Solves `part` of parsed input with the named solver, or returns `None` if there is none.
+    fn solve(
+        &self,
+        part: Part,
+        variant: &str,
+        input: &D,
+        params: &P,
+        ctx: &Context,
+    ) -> Option<AnswerResult> {
+        let solved = match part {
+            Part::First => {
+                let solve = if variant == DEFAULT_VARIANT {
+                    self.calc.part1
+                } else {
+                    self.variants
+                        .part1
+                        .iter()
+                        .find(|v| v.name == variant)?
+                        .solve
+                };
+                self.scoped(Phase::Part1, || render(solve(input, params, ctx)))
+            }
+            Part::Second => {
+                let solve = if variant == DEFAULT_VARIANT {
+                    self.calc.part2
+                } else {
+                    self.variants
+                        .part2
+                        .iter()
+                        .find(|v| v.name == variant)?
+                        .solve
+                };
+                self.scoped(Phase::Part2, || render(solve(input, params, ctx)))
+            }
+        };
+        Some(solved)
+    }
+}
+
+/// The parsed input and parameters of a [`Day`], as returned by [`Calculable::load`].
+struct Parsed<'a, D: 'static, O1: 'static, O2: 'static, P: 'static> {
+    day: &'a Day<D, O1, O2, P>,
+    input: D,
+    params: P,
+}
+
+impl<D, O1, O2, P> Loaded for Parsed<'_, D, O1, O2, P>
+where
+    D: std::fmt::Display,
+    O1: std::fmt::Display,
+    O2: std::fmt::Display,
+{
+    fn parsed(&self) -> String {
+        self.input.to_string()
+    }
+    fn solve(&self, part: Part, variant: &str, ctx: &Context) -> Option<AnswerResult> {
+        self.day
+            .solve(part, variant, &self.input, &self.params, ctx)
     }
-    fn both_func(&self) -> Rc<dyn Fn(&str) -> DayResult> {
+}
+
+impl<D, O1, O2, P> Calculable for Day<D, O1, O2, P>
+where
+    D: 'static + std::fmt::Display,
+    O1: 'static + std::fmt::Display,
+    O2: 'static + std::fmt::Display,
+    P: 'static + Params,
+{
+    fn calc(&self, part: Part, input: &str, params: &ParamOverrides) -> ParseResult<AnswerResult> {
+        let (input, params) = self.prepare(input, params)?;
+        // Every day has a default solver, so `solve` only returns `None` for unknown variants.
+        let solve = |part| self.solve(part, DEFAULT_VARIANT, &input, &params, &Context::none());
+        Ok(solve(part).unwrap())
+    }
+    fn both(&self, input: &str, params: &ParamOverrides) -> DayResult {
+        let (input, params) = self.prepare(input, params)?;
+        let solve = |part| self.solve(part, DEFAULT_VARIANT, &input, &params, &Context::none());
+        Ok((solve(Part::First).unwrap(), solve(Part::Second).unwrap()))
+    }
+    fn both_func(&self) -> DayFunc {
         let parse = self.calc.parse;
         let part1 = self.calc.part1;
         let part2 = self.calc.part2;
-        Rc::new(move |input: &str| {
-            let input = parse(input)?;
+        let (year, day) = (self.year, self.day);
+        Rc::new(move |input: &str, params: &ParamOverrides| {
+            let scoped = |phase| Scope { year, day, phase };
+            let params: P = apply_params(day, params)?;
+            let input = logging::scoped(scoped(Phase::Parse), || parse(input))
+                .map_err(|e| e.for_day(day))?;
             Ok((
-                part1(&input).answer.to_string(),
-                part2(&input).answer.to_string(),
+                logging::scoped(scoped(Phase::Part1), || {
+                    render(part1(&input, &params, &Context::none()))
+                }),
+                logging::scoped(scoped(Phase::Part2), || {
+                    render(part2(&input, &params, &Context::none()))
+                }),
             ))
         })
     }
+    fn variants(&self, part: Part) -> Vec<&'static str> {
+        let names: Vec<&'static str> = match part {
+            Part::First => self.variants.part1.iter().map(|v| v.name).collect(),
+            Part::Second => self.variants.part2.iter().map(|v| v.name).collect(),
+        };
+        std::iter::once(DEFAULT_VARIANT).chain(names).collect()
+    }
+    fn calc_variant(
+        &self,
+        part: Part,
+        variant: &str,
+        input: &str,
+        params: &ParamOverrides,
+        ctx: &Context,
+    ) -> Option<ParseResult<AnswerResult>> {
+        if !self.variants(part).contains(&variant) {
+            return None;
+        }
+        let (input, params) = match self.prepare(input, params) {
+            Ok(prepared) => prepared,
+            Err(e) => return Some(Err(e)),
+        };
+        self.solve(part, variant, &input, &params, ctx).map(Ok)
+    }
+    fn load(&self, input: &str, params: &ParamOverrides) -> ParseResult<Box<dyn Loaded + '_>> {
+        let (input, params) = self.prepare(input, params)?;
+        Ok(Box::new(Parsed {
+            day: self,
+            input,
+            params,
+        }))
+    }
+    fn all_variants(
+        &self,
+        input: &str,
+        params: &ParamOverrides,
+    ) -> ParseResult<(VariantAnswers, VariantAnswers)> {
+        let (input, params) = self.prepare(input, params)?;
+        let answers = |part| -> VariantAnswers {
+            self.variants(part)
+                .into_iter()
+                .map(|name| {
+                    let answer = self.solve(part, name, &input, &params, &Context::none());
+                    (name, answer.unwrap())
+                })
+                .collect()
+        };
+        let (part1, part2) = (answers(Part::First), answers(Part::Second));
+        Ok((part1, part2))
+    }
+    fn probed(
+        &self,
+        input: &str,
+        params: &ParamOverrides,
+        ctx: &Context,
+        probe: &mut dyn Probe,
+    ) -> DayResult {
+        let params: P = apply_params(self.day, params)?;
+        // The scope is entered before each phase starts, so that it is not timed.
+        let input = self.scoped(Phase::Parse, || {
+            probe.start(Phase::Parse);
+            let input = (self.calc.parse)(std::hint::black_box(input));
+            probe.stop(Phase::Parse);
+            input
+        });
+        let input = input.map_err(|e| e.for_day(self.day))?;
+        let part1 = self.scoped(Phase::Part1, || {
+            probe.start(Phase::Part1);
+            let part1 = (self.calc.part1)(&input, &params, ctx);
+            probe.stop(Phase::Part1);
+            part1
+        });
+        let part2 = self.scoped(Phase::Part2, || {
+            probe.start(Phase::Part2);
+            let part2 = (self.calc.part2)(&input, &params, ctx);
+            probe.stop(Phase::Part2);
+            part2
+        });
+        Ok((render(part1), render(part2)))
+    }
 }
 
-pub trait DayTrait: Printable + Calculable + Send {
-    /// Reads the input file for the given day.
-    fn input(&self) -> String;
+pub trait DayTrait: Printable + Calculable + Send + Sync {
+    /// Reads the input for the given day from the standard locations.
+    fn input(&self) -> Result<String, InputError> {
+        self.input_from(&ChainInput::standard())
+    }
+    /// Reads the input for the given day from `source`.
+    fn input_from(&self, source: &dyn InputSource) -> Result<String, InputError>;
+    /// Writes random inputs of any size, if the day has a generator.
+    fn generator(&self) -> Option<&Generator>;
 }
 
-impl<D: 'static, O: 'static + std::fmt::Display, const C: usize, const F: usize, const S: usize>
-    DayTrait for Day<D, O, C, F, S>
+impl<D, O1, O2, P> DayTrait for Day<D, O1, O2, P>
+where
+    D: 'static + std::fmt::Display,
+    O1: 'static + std::fmt::Display,
+    O2: 'static + std::fmt::Display,
+    P: 'static + Params,
 {
-    fn input(&self) -> String {
-        match fs::read_to_string(format!("inputs/day{:02}.txt", self.day))
-            .or_else(|_| fs::read_to_string(format!("../inputs/day{:02}.txt", self.day)))
-        {
-            Err(e) => panic!("Err: {}, inputs/day{:02}.txt", e, self.day),
-            Ok(string) => string,
-        }
+    fn input_from(&self, source: &dyn InputSource) -> Result<String, InputError> {
+        source
+            .read(self.year, self.day)
+            .map_err(|searched| InputError::new(self.year, self.day, searched))
+    }
+    fn generator(&self) -> Option<&Generator> {
+        self.generator.as_ref()
     }
 }
 
-pub fn get_days() -> BTreeMap<usize, Box<dyn DayTrait + 'static>> {
-    let mut days: BTreeMap<usize, Box<dyn DayTrait + 'static>> = BTreeMap::new();
-    days.insert(0, Box::new(day00_template::DAY));
-    days.insert(1, Box::new(day01::DAY));
-    days.insert(2, Box::new(day02::DAY));
-    days.insert(3, Box::new(day03::DAY));
-    days.insert(4, Box::new(day04::DAY));
-    days.insert(5, Box::new(day05::DAY));
-    days.insert(6, Box::new(day06::DAY));
-    days.insert(7, Box::new(day07::DAY));
-    days.insert(8, Box::new(day08::DAY));
-    days.insert(9, Box::new(day09::DAY));
-    // days.insert(10, Box::new(day10::DAY));
-    // days.insert(11, Box::new(day11::DAY));
-    // days.insert(12, Box::new(day12::DAY));
-    days
+/// The solved days of one year, keyed by day number.
+pub type Days = BTreeMap<usize, Box<dyn DayTrait + 'static>>;
+
+/// One year of puzzles.
+pub struct Year {
+    pub year: usize,
+    pub days: Days,
+}
+
+pub fn get_years() -> BTreeMap<usize, Year> {
+    let mut years = BTreeMap::new();
+    register_years(&mut years);
+    years
 }
 
 #[macro_export]
@@ -255,3 +548,98 @@ macro_rules! regex_once {
         RE.get_or_init(|| regex::Regex::new($re).unwrap())
     }};
 }
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use super::*;
+
+    struct Numbers(Vec<usize>);
+
+    impl std::fmt::Display for Numbers {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+
+    fn parse(input: &str) -> ParseResult<Numbers> {
+        parser::parse_lines(input, |line| parser::parse_token(line, 1, "a number")).map(Numbers)
+    }
+
+    fn count(numbers: &Numbers, _params: &(), _ctx: &Context) -> SolveResult<usize> {
+        Ok(PartOutput {
+            answer: numbers.0.len(),
+        })
+    }
+
+    fn joined(numbers: &Numbers, _params: &(), _ctx: &Context) -> SolveResult<String> {
+        Ok(PartOutput {
+            answer: numbers
+                .0
+                .iter()
+                .map(usize::to_string)
+                .collect::<Vec<_>>()
+                .join(","),
+        })
+    }
+
+    const MIXED: Day<Numbers, usize, String> = Day {
+        year: 2025,
+        day: 0,
+        title: "Mixed",
+        display: ("{answer}", "{answer}"),
+        calc: DayCalc {
+            parse,
+            part1: count,
+            part2: joined,
+        },
+        variants: Variants::none(),
+        generator: None,
+        examples: &[Example::both("1\n2\n3\n", "3", "1,2,3")],
+    };
+
+    #[test]
+    fn test_mixed_answer_types() {
+        let (part1, part2) = MIXED.both(MIXED.examples().first(), &[]).unwrap();
+        assert_eq!(part1.unwrap(), "3");
+        assert_eq!(part2.unwrap(), "1,2,3");
+    }
+
+    #[test]
+    fn test_load_once_solve_many() {
+        let loaded = MIXED.load("4\n5\n", &[]).unwrap();
+        assert_eq!(loaded.parsed(), "[4, 5]");
+        for _ in 0..2 {
+            assert_eq!(
+                loaded.solve(Part::First, DEFAULT_VARIANT, &Context::none()),
+                Some(Ok("2".to_string()))
+            );
+        }
+        assert_eq!(
+            loaded.solve(Part::Second, DEFAULT_VARIANT, &Context::none()),
+            Some(Ok("4,5".to_string()))
+        );
+        assert_eq!(loaded.solve(Part::First, "missing", &Context::none()), None);
+        assert!(MIXED.load("4\nx\n", &[]).is_err());
+    }
+
+    #[test]
+    fn test_variants_agree_on_examples() {
+        for (year_num, year) in get_years() {
+            for (day_num, day) in year.days {
+                let example = day.examples().first_example();
+                let (part1, part2) = day.all_variants(example.input, example.params).unwrap();
+                for (part, answers) in [(1, part1), (2, part2)] {
+                    let (_, expected) = &answers[0];
+                    for (name, answer) in &answers[1..] {
+                        assert_eq!(
+                            answer, expected,
+                            "{year_num} day {day_num} part {part} variant {name} disagrees"
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,84 @@
+//! Which day and phase the current thread is working on, for log lines to be filtered and
+//! tagged by.
+//!
+//! Solvers log through the `log` macros as usual; [`Day`](crate::Day) enters the scope around
+//! parsing and each part, and the logger reads it back with [`current`].
+
+use std::cell::Cell;
+use std::fmt::Display;
+
+use crate::bench::Phase;
+
+/// A day's parse or part, as it runs on some thread.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Scope {
+    pub year: usize,
+    pub day: usize,
+    pub phase: Phase,
+}
+
+impl Display for Scope {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let phase = match self.phase {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part 1",
+            Phase::Part2 => "part 2",
+            Phase::Total => "total",
+        };
+        write!(f, "{} day {} {phase}", self.year, self.day)
+    }
+}
+
+thread_local! {
+    static CURRENT: Cell<Option<Scope>> = const { Cell::new(None) };
+}
+
+/// The scope the current thread is in, if any.
+pub fn current() -> Option<Scope> {
+    CURRENT.get()
+}
+
+/// Calls `f` within `scope`, restoring the enclosing scope afterwards, even if `f` panics.
+pub fn scoped<T>(scope: Scope, f: impl FnOnce() -> T) -> T {
+    struct Restore(Option<Scope>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            CURRENT.set(self.0);
+        }
+    }
+
+    let _restore = Restore(CURRENT.replace(Some(scope)));
+    f()
+}
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use super::*;
+
+    #[test]
+    fn test_scoped() {
+        let parse = Scope {
+            year: 2025,
+            day: 8,
+            phase: Phase::Parse,
+        };
+        let part2 = Scope {
+            phase: Phase::Part2,
+            ..parse
+        };
+        assert_eq!(current(), None);
+        scoped(parse, || {
+            assert_eq!(current(), Some(parse));
+            scoped(part2, || {
+                assert_eq!(current().unwrap().to_string(), "2025 day 8 part 2")
+            });
+            assert_eq!(current(), Some(parse));
+        });
+        assert_eq!(current(), None);
+        let _ = crate::panic::isolated(|| scoped(part2, || panic!("unwinding")));
+        assert_eq!(current(), None);
+    }
+}
@@ -0,0 +1,96 @@
+//! Running solvers so that a panic is reported as an error rather than ending the run.
+
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::fmt::Display;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+/// Why and where a solver panicked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Panic {
+    pub message: String,
+    /// The source location, as `file:line:column`.
+    pub location: Option<String>,
+}
+
+impl Display for Panic {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "panicked at {location}: {}", self.message),
+            None => write!(f, "panicked: {}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for Panic {}
+
+thread_local! {
+    /// How many calls of [`isolated`] this thread is inside of.
+    static ISOLATED: Cell<usize> = const { Cell::new(0) };
+    /// The location of the last panic caught on this thread.
+    static LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Wraps the panic hook to record where isolated panics happen, instead of printing them.
+///
+/// Panics outside of [`isolated`] still go to the previous hook.
+fn install_hook() {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if ISOLATED.get() > 0 {
+                let location = info.location().map(|location| location.to_string());
+                LOCATION.set(location);
+            } else {
+                previous(info);
+            }
+        }));
+    });
+}
+
+fn message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".to_string()
+    }
+}
+
+/// Calls `f`, returning the panic instead if it panics.
+pub fn isolated<T>(f: impl FnOnce() -> T) -> Result<T, Panic> {
+    install_hook();
+    ISOLATED.set(ISOLATED.get() + 1);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    ISOLATED.set(ISOLATED.get() - 1);
+    result.map_err(|payload| Panic {
+        message: message(payload.as_ref()),
+        location: LOCATION.take(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use super::*;
+
+    #[test]
+    fn test_isolated() {
+        assert_eq!(isolated(|| 6 * 7), Ok(42));
+        let panic = isolated(|| -> usize { panic!("no {} here", "answer") }).unwrap_err();
+        assert_eq!(panic.message, "no answer here");
+        assert!(
+            panic
+                .location
+                .unwrap()
+                .starts_with("advent2025-lib/src/panic.rs:")
+        );
+        let numbers = [1, 2, 3];
+        let panic = isolated(|| numbers[std::hint::black_box(4)]).unwrap_err();
+        assert!(panic.message.contains("index out of bounds"));
+    }
+}
@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 use std::str::FromStr;
 
+use crate::{ParseError, ParseResult};
+
 const DELIMITERS: [&str; 6] = ["\n\n", "\n", ",", " ", ":", "-"];
 
 pub fn read_vec1<T: FromStr>(input: &str) -> Result<Vec<T>, T::Err> {
@@ -70,6 +72,84 @@ pub fn read_map<T: FromChar>(input: &str) -> Result<HashMap<(usize, usize), T>,
     Ok(map)
 }
 
+/// Parses every line of `input` with `f`, locating any error at its line.
+pub fn parse_lines<T, F>(input: &str, f: F) -> ParseResult<Vec<T>>
+where
+    F: FnMut(&str) -> ParseResult<T>,
+{
+    parse_lines_from(input.lines(), 0, f)
+}
+
+/// Like [`parse_lines`], for lines starting at the 0-based line index `first_line`.
+pub fn parse_lines_from<'a, T, F>(
+    lines: impl IntoIterator<Item = &'a str>,
+    first_line: usize,
+    mut f: F,
+) -> ParseResult<Vec<T>>
+where
+    F: FnMut(&str) -> ParseResult<T>,
+{
+    lines
+        .into_iter()
+        .enumerate()
+        .map(|(idx, line)| f(line).map_err(|e| e.at_line(first_line + idx + 1, line)))
+        .collect()
+}
+
+/// Splits `input` into blank-line separated sections, each with its 0-based first line index.
+pub fn sections(input: &str) -> Vec<(usize, Vec<&str>)> {
+    let mut sections = vec![(0, Vec::new())];
+    for (idx, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            sections.push((idx + 1, Vec::new()));
+        } else {
+            sections.last_mut().unwrap().1.push(line);
+        }
+    }
+    sections
+}
+
+/// Splits `line` on `delim`, yielding each token with its 1-based column in characters.
+pub fn split_columns(line: &str, delim: char) -> impl Iterator<Item = (usize, &str)> {
+    line.split(delim).scan(1, |column, token| {
+        let start = *column;
+        *column += token.chars().count() + 1;
+        Some((start, token))
+    })
+}
+
+/// Splits `line` on ASCII whitespace, yielding each token with its 1-based column in
+/// characters.
+pub fn whitespace_columns(line: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut after_blank = true;
+    line.char_indices()
+        .enumerate()
+        .filter_map(move |(column, (start, c))| {
+            let blank = c.is_ascii_whitespace();
+            let starts_token = after_blank && !blank;
+            after_blank = blank;
+            starts_token.then(|| {
+                let end = line[start..]
+                    .find(|c: char| c.is_ascii_whitespace())
+                    .map_or(line.len(), |len| start + len);
+                (column + 1, &line[start..end])
+            })
+        })
+}
+
+/// Parses `token` found at the 1-based `column`, underlining the whole token on failure.
+pub fn parse_token<T>(token: &str, column: usize, expected: &str) -> ParseResult<T>
+where
+    T: FromStr,
+    ParseError: From<T::Err>,
+{
+    token.parse().map_err(|e| {
+        ParseError::from(e)
+            .at_column(column, token.chars().count())
+            .expected(expected)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use std::fs::read_to_string;
@@ -80,7 +160,47 @@ mod tests {
 
     #[test]
     fn test_example_day01() {
-        let input = read_to_string("../examples/day01.txt").unwrap();
+        let input = read_to_string("../examples/2025/day01.txt").unwrap();
         assert_eq!(read_vec2::<char>(&input).unwrap()[0], vec!['L', '6', '8'],);
     }
+
+    #[test]
+    fn test_parse_lines_locates_error() {
+        let error = parse_lines("1\n2\nx\n", |line| {
+            parse_token::<usize>(line, 1, "a number")
+        })
+        .unwrap_err();
+        assert_eq!(error.line(), Some(3));
+        assert_eq!(error.column(), Some(1));
+        assert_eq!(error.snippet(), Some("x"));
+    }
+
+    #[test]
+    fn test_sections() {
+        assert_eq!(
+            sections("a\nb\n\nc\n"),
+            vec![(0, vec!["a", "b"]), (3, vec!["c"])]
+        );
+    }
+
+    #[test]
+    fn test_columns() {
+        assert_eq!(
+            split_columns("3-5,10-14", ',').collect::<Vec<_>>(),
+            vec![(1, "3-5"), (5, "10-14")]
+        );
+        assert_eq!(
+            whitespace_columns(" 12  3 ").collect::<Vec<_>>(),
+            vec![(2, "12"), (6, "3")]
+        );
+        // Columns count characters, not bytes, after a multibyte prefix.
+        assert_eq!(
+            split_columns("é→,10-14", ',').collect::<Vec<_>>(),
+            vec![(1, "é→"), (4, "10-14")]
+        );
+        assert_eq!(
+            whitespace_columns("é→ 12\t 3").collect::<Vec<_>>(),
+            vec![(1, "é→"), (4, "12"), (8, "3")]
+        );
+    }
 }
@@ -0,0 +1,211 @@
+//! Progress reported by long-running solvers, for whoever is watching them, and the means to
+//! stop them.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::SolveError;
+use crate::visualize::{Frame, Visualizer};
+
+/// How many iterations of a hot loop [`Context::checkpoint`] lets pass between reports and
+/// checks, few enough to stop promptly and many enough to cost next to nothing.
+pub const CHECK_INTERVAL: u64 = 1 << 10;
+
+/// Receives what a solver reports through its [`Context`].
+pub trait Reporter: Sync {
+    /// The solver has started the step called `name`.
+    fn phase(&self, name: &str);
+    /// The solver has done `done` of the `total` units of work in its current step.
+    fn progress(&self, done: u64, total: u64);
+}
+
+/// Tells a solver to stop early, once [`CancelToken::cancel`] is called or its deadline passes.
+#[derive(Debug, Default)]
+pub struct CancelToken {
+    cancelled: AtomicBool,
+    deadline: Option<Instant>,
+}
+
+impl CancelToken {
+    /// A token that cancels itself `timeout` from now.
+    pub fn with_timeout(timeout: Duration) -> Self {
+        CancelToken {
+            cancelled: AtomicBool::new(false),
+            deadline: Some(Instant::now() + timeout),
+        }
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+            || self
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+}
+
+/// The handle passed to every part function, to report how far along it is, to show its
+/// intermediate states and to learn when to give up.
+///
+/// Reports go nowhere unless the caller is watching, so solvers can report freely, though in
+/// hot loops they should only report, or check for cancellation, every so often.
+#[derive(Clone, Copy, Default)]
+pub struct Context<'a> {
+    reporter: Option<&'a dyn Reporter>,
+    cancel: Option<&'a CancelToken>,
+    visualizer: Option<&'a dyn Visualizer>,
+}
+
+impl<'a> Context<'a> {
+    /// A context that nobody is watching or cancelling, as used in tests.
+    pub const fn none() -> Self {
+        Context {
+            reporter: None,
+            cancel: None,
+            visualizer: None,
+        }
+    }
+
+    pub fn new(reporter: &'a dyn Reporter) -> Self {
+        Context {
+            reporter: Some(reporter),
+            cancel: None,
+            visualizer: None,
+        }
+    }
+
+    pub const fn with_cancel(self, cancel: &'a CancelToken) -> Self {
+        Context {
+            cancel: Some(cancel),
+            ..self
+        }
+    }
+
+    pub const fn with_visualizer(self, visualizer: &'a dyn Visualizer) -> Self {
+        Context {
+            visualizer: Some(visualizer),
+            ..self
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel.is_some_and(CancelToken::is_cancelled)
+    }
+
+    /// Fails with [`SolveError::Cancelled`] once the solver should stop, for it to return early
+    /// with `?`.
+    pub fn check(&self) -> Result<(), SolveError> {
+        if self.is_cancelled() {
+            Err(SolveError::Cancelled)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Reports progress and checks whether to stop on every [`CHECK_INTERVAL`]th iteration of a
+    /// hot loop, `done` being the number of iterations so far.
+    pub fn checkpoint(&self, done: u64, total: u64) -> Result<(), SolveError> {
+        if !done.is_multiple_of(CHECK_INTERVAL) {
+            return Ok(());
+        }
+        self.progress(done, total);
+        self.check()
+    }
+
+    pub fn phase(&self, name: &str) {
+        if let Some(reporter) = self.reporter {
+            reporter.phase(name);
+        }
+    }
+
+    pub fn progress(&self, done: u64, total: u64) {
+        if let Some(reporter) = self.reporter {
+            reporter.progress(done, total);
+        }
+    }
+
+    /// Whether frames are wanted, for solvers to skip keeping the state they are drawn from.
+    pub fn is_visualized(&self) -> bool {
+        self.visualizer.is_some()
+    }
+
+    /// Emits the frame built by `frame`, which is only called if frames are wanted.
+    pub fn frame(&self, frame: impl FnOnce() -> Frame) {
+        if let Some(visualizer) = self.visualizer {
+            visualizer.frame(frame());
+        }
+    }
+}
+
+impl std::fmt::Debug for Context<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("Context")
+            .field("watched", &self.reporter.is_some())
+            .field("cancelled", &self.is_cancelled())
+            .field("visualized", &self.is_visualized())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use test_log::test;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct Recorder(Mutex<Vec<String>>);
+
+    impl Reporter for Recorder {
+        fn phase(&self, name: &str) {
+            self.0.lock().unwrap().push(name.to_string());
+        }
+
+        fn progress(&self, done: u64, total: u64) {
+            self.0.lock().unwrap().push(format!("{done}/{total}"));
+        }
+    }
+
+    #[test]
+    fn test_context_reports() {
+        let recorder = Recorder::default();
+        let ctx = Context::new(&recorder);
+        ctx.phase("sorting");
+        ctx.progress(3, 10);
+        Context::none().progress(4, 10);
+        assert_eq!(*recorder.0.lock().unwrap(), vec!["sorting", "3/10"]);
+    }
+
+    #[test]
+    fn test_cancel() {
+        let token = CancelToken::default();
+        let ctx = Context::none().with_cancel(&token);
+        assert_eq!(ctx.check(), Ok(()));
+        token.cancel();
+        assert_eq!(ctx.check(), Err(SolveError::Cancelled));
+        assert_eq!(Context::none().check(), Ok(()));
+
+        let expired = CancelToken::with_timeout(Duration::ZERO);
+        assert!(Context::none().with_cancel(&expired).is_cancelled());
+        assert!(!CancelToken::with_timeout(Duration::from_secs(60)).is_cancelled());
+    }
+
+    #[test]
+    fn test_checkpoint() {
+        let recorder = Recorder::default();
+        let token = CancelToken::default();
+        let ctx = Context::new(&recorder).with_cancel(&token);
+        for done in 0..=CHECK_INTERVAL {
+            assert_eq!(ctx.checkpoint(done, 5000), Ok(()));
+        }
+        assert_eq!(*recorder.0.lock().unwrap(), vec!["0/5000", "1024/5000"]);
+        token.cancel();
+        assert_eq!(ctx.checkpoint(1, 5000), Ok(()));
+        assert_eq!(ctx.checkpoint(2048, 5000), Err(SolveError::Cancelled));
+    }
+}
@@ -0,0 +1,238 @@
+//! Generates the files of a new day from one of the templates in `templates/`.
+//!
+//! The new module is declared in its year's `mod.rs`, from which `build.rs` registers it, and
+//! its tests fail until both parts are solved and their answers recorded.
+
+use std::fmt::Display;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use strum::VariantNames as _;
+use strum_macros::{EnumString, VariantNames};
+
+/// The shape of input a template parses.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, EnumString, VariantNames)]
+#[strum(serialize_all = "lowercase")]
+pub enum Flavour {
+    /// One record of whitespace-separated numbers per line.
+    #[default]
+    Lines,
+    /// A grid of characters.
+    Grid,
+    /// Blank-line separated sections of comma-separated numbers.
+    Sections,
+}
+
+impl Flavour {
+    /// The name of every flavour, as accepted by [`str::parse`].
+    pub fn names() -> &'static [&'static str] {
+        Self::VARIANTS
+    }
+
+    fn template(self) -> &'static str {
+        match self {
+            Flavour::Lines => include_str!("../templates/lines.rs.tmpl"),
+            Flavour::Grid => include_str!("../templates/grid.rs.tmpl"),
+            Flavour::Sections => include_str!("../templates/sections.rs.tmpl"),
+        }
+    }
+
+    /// A small input in the template's format, to be replaced by the puzzle's example.
+    fn example(self) -> &'static str {
+        match self {
+            Flavour::Lines => include_str!("../templates/lines.txt"),
+            Flavour::Grid => include_str!("../templates/grid.txt"),
+            Flavour::Sections => include_str!("../templates/sections.txt"),
+        }
+    }
+
+    /// Renders the source of day `day` of `year`.
+    pub fn render(self, year: usize, day: usize) -> String {
+        self.template()
+            .replace("{{year}}", &year.to_string())
+            .replace("{{day02}}", &format!("{day:02}"))
+            .replace("{{day}}", &day.to_string())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScaffoldError {
+    /// No workspace was found, with every location that was searched.
+    NotFound(Vec<String>),
+    /// The file already exists, and was left alone.
+    Exists(PathBuf),
+    Io(PathBuf, String),
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ScaffoldError::NotFound(searched) => {
+                write!(f, "no workspace found, searched:")?;
+                for location in searched {
+                    write!(f, "\n  {location}")?;
+                }
+                Ok(())
+            }
+            ScaffoldError::Exists(path) => write!(f, "{} already exists", path.display()),
+            ScaffoldError::Io(path, e) => write!(f, "{} ({e})", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+/// The files of a new day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scaffold {
+    pub year: usize,
+    pub day: usize,
+    pub source: PathBuf,
+    /// The year's `mod.rs`, which declares its days.
+    pub modules: PathBuf,
+    pub example: PathBuf,
+    pub input: PathBuf,
+}
+
+impl Scaffold {
+    /// The files of day `day` of `year`, in the workspace at `root`.
+    pub fn new(root: &Path, year: usize, day: usize) -> Self {
+        let file_name = format!("day{day:02}");
+        let year_dir = root.join("advent2025-lib/src").join(format!("y{year}"));
+        Scaffold {
+            year,
+            day,
+            source: year_dir.join(format!("{file_name}.rs")),
+            modules: year_dir.join("mod.rs"),
+            example: root
+                .join("examples")
+                .join(year.to_string())
+                .join(format!("{file_name}.txt")),
+            input: root
+                .join("inputs")
+                .join(year.to_string())
+                .join(format!("{file_name}.txt")),
+        }
+    }
+
+    /// Finds the workspace in the current directory or its parent.
+    pub fn locate(year: usize, day: usize) -> Result<Self, ScaffoldError> {
+        Ok(Self::new(&workspace()?, year, day))
+    }
+
+    /// Writes the source and example, and an empty input unless one was already downloaded,
+    /// and declares the source's module in the year's `mod.rs`.
+    ///
+    /// Nothing is written if the source or example already exists.
+    pub fn create(&self, flavour: Flavour) -> Result<(), ScaffoldError> {
+        for path in [&self.source, &self.example] {
+            if path.exists() {
+                return Err(ScaffoldError::Exists(path.clone()));
+            }
+        }
+        write(&self.source, &flavour.render(self.year, self.day))?;
+        declare(&self.modules, self.year, &format!("day{:02}", self.day))?;
+        write(&self.example, flavour.example())?;
+        if !self.input.exists() {
+            write(&self.input, "")?;
+        }
+        Ok(())
+    }
+}
+
+/// The root of the workspace, which is the current directory or its parent.
+pub fn workspace() -> Result<PathBuf, ScaffoldError> {
+    let mut searched = Vec::new();
+    for root in [PathBuf::from("."), PathBuf::from("..")] {
+        let lib = root.join("advent2025-lib/src");
+        if lib.is_dir() {
+            return Ok(root);
+        }
+        searched.push(format!("{} (not a directory)", lib.display()));
+    }
+    Err(ScaffoldError::NotFound(searched))
+}
+
+/// Adds `pub mod {module};` to the year's `mod.rs` among the other declarations in order,
+/// creating the file for a new year.
+fn declare(path: &Path, year: usize, module: &str) -> Result<(), ScaffoldError> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == ErrorKind::NotFound => {
+            format!("//! The puzzles of {year}, one module per day.\n\n")
+        }
+        Err(e) => return Err(ScaffoldError::Io(path.to_path_buf(), e.to_string())),
+    };
+    let declaration = format!("pub mod {module};");
+    let mut lines: Vec<&str> = contents.lines().collect();
+    if lines.contains(&declaration.as_str()) {
+        return Ok(());
+    }
+    let at = lines
+        .iter()
+        .position(|line| line.starts_with("pub mod ") && *line > declaration.as_str())
+        .unwrap_or(lines.len());
+    lines.insert(at, &declaration);
+    write(path, &(lines.join("\n") + "\n"))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), ScaffoldError> {
+    let io_error = |e: std::io::Error| ScaffoldError::Io(path.to_path_buf(), e.to_string());
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(io_error)?;
+    }
+    fs::write(path, contents).map_err(io_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use test_log::test;
+
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        for flavour in [Flavour::Lines, Flavour::Grid, Flavour::Sections] {
+            let source = flavour.render(2026, 7);
+            assert!(!source.contains("{{"), "{flavour:?} has a placeholder left");
+            assert!(source.contains("    year: 2026,\n    day: 7,\n"));
+            assert!(source.contains("\"../../../examples/2026/day07.txt\""));
+        }
+    }
+
+    #[test]
+    fn test_flavour_names() {
+        assert_eq!(Flavour::from_str("grid"), Ok(Flavour::Grid));
+        assert!(Flavour::from_str("table").is_err());
+    }
+
+    #[test]
+    fn test_create_refuses_to_overwrite() {
+        let root = std::env::temp_dir().join(format!("advent-scaffold-{}", std::process::id()));
+        let scaffold = Scaffold::new(&root, 2026, 7);
+        scaffold.create(Flavour::Grid).unwrap();
+        assert_eq!(
+            fs::read_to_string(&scaffold.example).unwrap(),
+            Flavour::Grid.example()
+        );
+        assert_eq!(fs::read_to_string(&scaffold.input).unwrap(), "");
+        let modules = fs::read_to_string(&scaffold.modules).unwrap();
+        assert!(modules.ends_with("\n\npub mod day07;\n"), "{modules}");
+        Scaffold::new(&root, 2026, 3)
+            .create(Flavour::Lines)
+            .unwrap();
+        let modules = fs::read_to_string(&scaffold.modules).unwrap();
+        assert!(
+            modules.ends_with("\n\npub mod day03;\npub mod day07;\n"),
+            "{modules}"
+        );
+        assert_eq!(
+            scaffold.create(Flavour::Grid),
+            Err(ScaffoldError::Exists(scaffold.source.clone()))
+        );
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,135 @@
+//! A bounded pool of worker threads, reporting each job as it starts and finishes.
+
+use std::collections::BTreeMap;
+use std::sync::{Mutex, mpsc};
+use std::time::{Duration, Instant};
+
+/// Work for the pool, which may borrow from the caller for as long as the pool runs.
+pub type Job<'a, T> = Box<dyn FnOnce() -> T + Send + 'a>;
+
+/// What happened to the job at an index of the list given to [`run`].
+#[derive(Debug)]
+pub enum Event<T> {
+    Started(usize),
+    Finished {
+        job: usize,
+        output: T,
+        /// How long the job kept its worker busy.
+        elapsed: Duration,
+    },
+}
+
+/// Runs `jobs` on at most `workers` threads, taking them in order as workers free up.
+///
+/// `on_event` is called on the calling thread, in the order events happen, and `run` returns
+/// once every job has finished.
+pub fn run<'a, T: Send>(jobs: Vec<Job<'a, T>>, workers: usize, mut on_event: impl FnMut(Event<T>)) {
+    let workers = workers.clamp(1, jobs.len().max(1));
+    let queue = Mutex::new(jobs.into_iter().enumerate());
+    std::thread::scope(|scope| {
+        let (sender, receiver) = mpsc::channel();
+        for _ in 0..workers {
+            let sender = sender.clone();
+            let queue = &queue;
+            scope.spawn(move || {
+                loop {
+                    let next = queue.lock().unwrap().next();
+                    let Some((job, work)) = next else {
+                        break;
+                    };
+                    let _ = sender.send(Event::Started(job));
+                    let started = Instant::now();
+                    let output = work();
+                    let elapsed = started.elapsed();
+                    let _ = sender.send(Event::Finished {
+                        job,
+                        output,
+                        elapsed,
+                    });
+                }
+            });
+        }
+        drop(sender);
+        for event in receiver {
+            on_event(event);
+        }
+    });
+}
+
+/// Releases items in the order of their index, whatever order they arrive in.
+#[derive(Debug)]
+pub struct InOrder<T> {
+    next: usize,
+    pending: BTreeMap<usize, T>,
+}
+
+impl<T> Default for InOrder<T> {
+    fn default() -> Self {
+        InOrder {
+            next: 0,
+            pending: BTreeMap::new(),
+        }
+    }
+}
+
+impl<T> InOrder<T> {
+    /// Adds the item at `idx`, and returns every item that no longer waits on an earlier one.
+    pub fn push(&mut self, idx: usize, item: T) -> Vec<T> {
+        self.pending.insert(idx, item);
+        let mut ready = Vec::new();
+        while let Some(item) = self.pending.remove(&self.next) {
+            ready.push(item);
+            self.next += 1;
+        }
+        ready
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use test_log::test;
+
+    use super::*;
+
+    #[test]
+    fn test_run_bounds_workers() {
+        let running = AtomicUsize::new(0);
+        let most = AtomicUsize::new(0);
+        let jobs: Vec<Job<usize>> = (0..12usize)
+            .map(|idx| {
+                let (running, most) = (&running, &most);
+                Box::new(move || {
+                    let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                    most.fetch_max(now, Ordering::SeqCst);
+                    std::thread::sleep(Duration::from_millis(5));
+                    running.fetch_sub(1, Ordering::SeqCst);
+                    idx * 10
+                }) as Job<usize>
+            })
+            .collect();
+        let mut started = 0;
+        let mut outputs = Vec::new();
+        run(jobs, 3, |event| match event {
+            Event::Started(_) => started += 1,
+            Event::Finished { job, output, .. } => outputs.push((job, output)),
+        });
+        outputs.sort();
+        assert_eq!(started, 12);
+        assert_eq!(
+            outputs,
+            (0..12).map(|idx| (idx, idx * 10)).collect::<Vec<_>>()
+        );
+        assert!(most.load(Ordering::SeqCst) <= 3);
+    }
+
+    #[test]
+    fn test_in_order() {
+        let mut in_order = InOrder::default();
+        assert!(in_order.push(2, 'c').is_empty());
+        assert!(in_order.push(1, 'b').is_empty());
+        assert_eq!(in_order.push(0, 'a'), vec!['a', 'b', 'c']);
+        assert_eq!(in_order.push(3, 'd'), vec!['d']);
+    }
+}
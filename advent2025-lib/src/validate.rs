@@ -0,0 +1,208 @@
+//! Checks of input and example files that need nothing but their text: whitespace that a
+//! download or an editor may have mangled, and a few statistics to eyeball.
+//!
+//! Whether the text parses is up to each day's [`Calculable::load`](crate::Calculable::load).
+
+use std::collections::BTreeSet;
+use std::fmt::Display;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+/// Whitespace that a parser may trip over, with the 1-based numbers of the lines it is on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Warning {
+    Empty,
+    CrLf(Vec<usize>),
+    TrailingSpace(Vec<usize>),
+    NoFinalNewline,
+    /// The number of blank lines after the last line of text.
+    TrailingBlankLines(usize),
+}
+
+/// Lists line numbers, up to a handful of them.
+fn describe_lines(lines: &[usize]) -> String {
+    const SHOWN: usize = 5;
+    let numbers: Vec<String> = lines.iter().take(SHOWN).map(usize::to_string).collect();
+    match (lines.len(), numbers.split_last()) {
+        (1, _) => format!("line {}", numbers[0]),
+        (count, _) if count > SHOWN => {
+            format!("lines {} and {} more", numbers.join(", "), count - SHOWN)
+        }
+        (_, Some((last, rest))) => format!("lines {} and {last}", rest.join(", ")),
+        (_, None) => "no lines".to_string(),
+    }
+}
+
+impl Display for Warning {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Warning::Empty => write!(f, "the file is empty"),
+            Warning::CrLf(lines) => write!(f, "CRLF line ending on {}", describe_lines(lines)),
+            Warning::TrailingSpace(lines) => {
+                write!(f, "trailing whitespace on {}", describe_lines(lines))
+            }
+            Warning::NoFinalNewline => write!(f, "no newline at the end of the file"),
+            Warning::TrailingBlankLines(count) => {
+                write!(f, "{count} blank line(s) at the end of the file")
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub bytes: usize,
+    pub lines: usize,
+    pub blank_lines: usize,
+    /// The length of the longest line in characters, without its line ending.
+    pub longest_line: usize,
+    /// The number of different characters, other than line endings.
+    pub distinct_chars: usize,
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{} lines ({} blank), longest {} chars, {} bytes, {} distinct chars",
+            self.lines, self.blank_lines, self.longest_line, self.bytes, self.distinct_chars
+        )
+    }
+}
+
+/// What the text of a file looks like, before any parser sees it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Inspection {
+    pub stats: Stats,
+    pub warnings: Vec<Warning>,
+}
+
+pub fn inspect(text: &str) -> Inspection {
+    let mut stats = Stats {
+        bytes: text.len(),
+        ..Stats::default()
+    };
+    let mut chars = BTreeSet::new();
+    let (mut crlf, mut trailing_space) = (Vec::new(), Vec::new());
+    let mut trailing_blank = 0;
+    for (idx, line) in text.split_inclusive('\n').enumerate() {
+        let mut content = line.strip_suffix('\n').unwrap_or(line);
+        if let Some(stripped) = content.strip_suffix('\r') {
+            crlf.push(idx + 1);
+            content = stripped;
+        }
+        if content.ends_with(char::is_whitespace) {
+            trailing_space.push(idx + 1);
+        }
+        if content.trim().is_empty() {
+            stats.blank_lines += 1;
+            trailing_blank += 1;
+        } else {
+            trailing_blank = 0;
+        }
+        stats.lines += 1;
+        stats.longest_line = stats.longest_line.max(content.chars().count());
+        chars.extend(content.chars());
+    }
+    stats.distinct_chars = chars.len();
+
+    let mut warnings = Vec::new();
+    if text.is_empty() {
+        warnings.push(Warning::Empty);
+    }
+    if !crlf.is_empty() {
+        warnings.push(Warning::CrLf(crlf));
+    }
+    if !trailing_space.is_empty() {
+        warnings.push(Warning::TrailingSpace(trailing_space));
+    }
+    if !text.is_empty() && !text.ends_with('\n') {
+        warnings.push(Warning::NoFinalNewline);
+    }
+    if trailing_blank > 0 && trailing_blank < stats.lines {
+        warnings.push(Warning::TrailingBlankLines(trailing_blank));
+    }
+    Inspection { stats, warnings }
+}
+
+/// The day of a file named like `day08.txt` or `day00_template.txt`.
+pub fn file_day(path: &Path) -> Option<usize> {
+    let name = path.file_name()?.to_str()?.strip_prefix("day")?;
+    let digits = name
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(name.len());
+    name[..digits].parse().ok()
+}
+
+/// The `.txt` files of days in `dir`, by day and then by name, or none if there is no `dir`.
+pub fn day_files(dir: &Path) -> std::io::Result<Vec<(usize, PathBuf)>> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+    let mut files = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "txt")
+            && let Some(day) = file_day(&path)
+        {
+            files.push((day, path));
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use super::*;
+
+    #[test]
+    fn test_inspect() {
+        let clean = inspect("123\n\n45 6\n");
+        assert_eq!(
+            clean.stats,
+            Stats {
+                bytes: 10,
+                lines: 3,
+                blank_lines: 1,
+                longest_line: 4,
+                distinct_chars: 7,
+            }
+        );
+        assert!(clean.warnings.is_empty());
+
+        let mangled = inspect("1 \r\n2\r\n3\n\n\n4");
+        assert_eq!(
+            mangled.warnings,
+            vec![
+                Warning::CrLf(vec![1, 2]),
+                Warning::TrailingSpace(vec![1]),
+                Warning::NoFinalNewline,
+            ]
+        );
+        assert_eq!(
+            mangled.warnings[0].to_string(),
+            "CRLF line ending on lines 1 and 2"
+        );
+        assert_eq!(
+            inspect("1\n2\n\n").warnings,
+            vec![Warning::TrailingBlankLines(1)]
+        );
+        assert_eq!(inspect("").warnings, vec![Warning::Empty]);
+        assert_eq!(
+            Warning::TrailingSpace((1..=8).collect()).to_string(),
+            "trailing whitespace on lines 1, 2, 3, 4, 5 and 3 more"
+        );
+    }
+
+    #[test]
+    fn test_file_day() {
+        assert_eq!(file_day(Path::new("inputs/2025/day08.txt")), Some(8));
+        assert_eq!(file_day(Path::new("day00_template.txt")), Some(0));
+        assert_eq!(file_day(Path::new("notes.txt")), None);
+    }
+}
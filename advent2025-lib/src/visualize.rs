@@ -0,0 +1,79 @@
+//! Pictures of a solver's intermediate states, for whoever is watching it to animate or export.
+//!
+//! Solvers emit frames through [`Context::frame`](crate::Context::frame), which only builds them
+//! when a [`Visualizer`] is attached.
+
+/// One picture of a solver's state, as rows of characters.
+///
+/// Renderers give each character its own colour, so frames should keep to a few of them, such
+/// as those of the puzzle's own diagrams.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    /// What the frame shows, such as the round of a simulation.
+    pub caption: String,
+    pub rows: Vec<String>,
+}
+
+impl Frame {
+    pub fn new(caption: impl Into<String>, rows: Vec<String>) -> Self {
+        Frame {
+            caption: caption.into(),
+            rows,
+        }
+    }
+
+    /// A frame of the lines of `text`, such as a diagram's `Display` output.
+    pub fn from_text(caption: impl Into<String>, text: &str) -> Self {
+        Self::new(caption, text.lines().map(str::to_string).collect())
+    }
+
+    /// The number of characters in the longest row.
+    pub fn width(&self) -> usize {
+        self.rows
+            .iter()
+            .map(|row| row.chars().count())
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+}
+
+/// Receives the frames a solver emits through its [`Context`](crate::Context).
+pub trait Visualizer: Sync {
+    fn frame(&self, frame: Frame);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use test_log::test;
+
+    use super::*;
+    use crate::Context;
+
+    #[derive(Default)]
+    struct Frames(Mutex<Vec<Frame>>);
+
+    impl Visualizer for Frames {
+        fn frame(&self, frame: Frame) {
+            self.0.lock().unwrap().push(frame);
+        }
+    }
+
+    #[test]
+    fn test_frames_only_built_when_visualized() {
+        let frames = Frames::default();
+        let ctx = Context::none().with_visualizer(&frames);
+        assert!(ctx.is_visualized());
+        ctx.frame(|| Frame::from_text("round 1", "@.@\n.@\n"));
+        Context::none().frame(|| unreachable!("nobody is watching"));
+        let frames = frames.0.into_inner().unwrap();
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].rows, vec!["@.@", ".@"]);
+        assert_eq!((frames[0].width(), frames[0].height()), (3, 2));
+    }
+}
@@ -0,0 +1,75 @@
+//! Noticing when files change, by polling their modification time and size.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// What a file looked like when last polled, or `None` if it could not be read.
+type Stamp = Option<(SystemTime, u64)>;
+
+fn stamp(path: &Path) -> Stamp {
+    let metadata = fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+/// A set of files, compared against how they looked on the previous poll.
+#[derive(Debug)]
+pub struct Watched {
+    files: Vec<(PathBuf, Stamp)>,
+}
+
+impl Watched {
+    pub fn new(paths: impl IntoIterator<Item = PathBuf>) -> Self {
+        Watched {
+            files: paths
+                .into_iter()
+                .map(|path| {
+                    let stamp = stamp(&path);
+                    (path, stamp)
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the files created, modified or removed since `new` or the previous call.
+    ///
+    /// A file saved twice within the resolution of its modification time, at the same size,
+    /// goes unnoticed.
+    pub fn changed(&mut self) -> Vec<&Path> {
+        let mut changed = Vec::new();
+        for (path, previous) in &mut self.files {
+            let current = stamp(path);
+            if current != *previous {
+                *previous = current;
+                changed.push(path.as_path());
+            }
+        }
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use super::*;
+
+    #[test]
+    fn test_changed() {
+        let dir = std::env::temp_dir().join(format!("advent-watch-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let (example, input) = (dir.join("example.txt"), dir.join("input.txt"));
+        fs::write(&example, "1\n").unwrap();
+        let mut watched = Watched::new([example.clone(), input.clone()]);
+        assert!(watched.changed().is_empty());
+
+        fs::write(&input, "1\n2\n").unwrap();
+        assert_eq!(watched.changed(), vec![input.as_path()]);
+        assert!(watched.changed().is_empty());
+
+        fs::write(&example, "1\n2\n3\n").unwrap();
+        fs::remove_file(&input).unwrap();
+        assert_eq!(watched.changed(), vec![example.as_path(), input.as_path()]);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
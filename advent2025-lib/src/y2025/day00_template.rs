@@ -1,10 +1,11 @@
 //! Day 0: Template
-//! Find and replace day00_template with dayXX, where XX is the day number.
-//! Find and replace something, Something, line, Line, cell, and Cell.
+//! A worked example of a day. Generate new days with `advent2025 new <day>` instead of copying it.
 
 use std::{collections::HashMap, fmt::Display, str::FromStr};
 
-use crate::{Day, DayCalc, Examples, ParseError, PartOutput};
+use crate::{
+    Context, Day, DayCalc, Example, ParseError, PartOutput, SolveResult, Variants, parser,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct RowColPos {
@@ -28,8 +29,11 @@ impl FromStr for Line {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.len() > 10 {
-            return Err(ParseError::Empty);
+        let cells = s.chars().count();
+        if cells > 10 {
+            return Err(ParseError::invalid("line is too long")
+                .at_column(11, cells - 10)
+                .expected("at most 10 cells"));
         }
         Ok(Line(s.chars().map(Cell).collect()))
     }
@@ -60,17 +64,14 @@ impl FromStr for Something {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let lines: Vec<&str> = s.lines().collect();
-        let mut splits = lines.split(|l| l.is_empty());
-        let lines = splits
+        let mut sections = parser::sections(s).into_iter();
+        let (first_line, lines) = sections.next().ok_or_else(ParseError::empty)?;
+        let lines = parser::parse_lines_from(lines, first_line, Line::from_str)?;
+        let (_, cells) = sections
             .next()
-            .unwrap()
-            .into_iter()
-            .copied()
-            .map(Line::from_str)
-            .collect::<Result<Vec<_>, _>>()?;
+            .ok_or_else(|| ParseError::empty().expected("a second section"))?;
         let mut map = HashMap::new();
-        for (row, line) in splits.next().unwrap().into_iter().copied().enumerate() {
+        for (row, line) in cells.into_iter().enumerate() {
             for (col, cell) in line.chars().enumerate() {
                 map.insert(RowColPos { row, col }, Cell(cell));
             }
@@ -88,19 +89,20 @@ impl Display for Something {
     }
 }
 
-pub fn part1(something: &Something) -> PartOutput<usize> {
-    PartOutput {
+pub fn part1(something: &Something, _params: &(), _ctx: &Context) -> SolveResult<usize> {
+    Ok(PartOutput {
         answer: something.len(),
-    }
+    })
 }
 
-pub fn part2(something: &Something) -> PartOutput<usize> {
-    PartOutput {
+pub fn part2(something: &Something, _params: &(), _ctx: &Context) -> SolveResult<usize> {
+    Ok(PartOutput {
         answer: something.map.len(),
-    }
+    })
 }
 
-pub const DAY: Day<Something, usize, 1, 0, 0> = Day {
+pub const DAY: Day<Something, usize, usize> = Day {
+    year: 2025,
     day: 0,
     title: "TITLE",
     display: (
@@ -112,7 +114,13 @@ pub const DAY: Day<Something, usize, 1, 0, 0> = Day {
         part1,
         part2,
     },
-    examples: Examples::single(include_str!("../../examples/day00_template.txt")),
+    variants: Variants::none(),
+    generator: None,
+    examples: &[Example::both(
+        include_str!("../../../examples/2025/day00_template.txt"),
+        "2",
+        "9",
+    )],
 };
 
 #[cfg(test)]
@@ -121,13 +129,15 @@ mod tests {
 
     use super::*;
     use crate::DayTrait as _;
+    use crate::Part;
     use crate::Printable as _;
+    use crate::answers::expected;
 
     #[test]
     fn test_example_part1() {
         let parse = DAY.calc.parse;
         let something = parse(DAY.examples().first()).unwrap();
-        let result = part1(&something);
+        let result = part1(&something, &(), &Context::none()).unwrap();
         assert_eq!(result.unwrap(), 2);
     }
 
@@ -135,15 +145,27 @@ mod tests {
     fn test_example_part2() {
         let parse = DAY.calc.parse;
         let something = parse(DAY.examples().second()).unwrap();
-        let result = part2(&something);
+        let result = part2(&something, &(), &Context::none()).unwrap();
         assert_eq!(result.unwrap(), 9);
     }
 
     #[test]
     fn test_main() {
         let parse = DAY.calc.parse;
-        let something = parse(&DAY.input()).unwrap();
-        assert_eq!(part1(&something).answer.to_string(), "3");
-        assert_eq!(part2(&something).answer.to_string(), "12");
+        let something = parse(&DAY.input().unwrap()).unwrap();
+        assert_eq!(
+            part1(&something, &(), &Context::none())
+                .unwrap()
+                .answer
+                .to_string(),
+            expected(DAY.year, DAY.day, Part::First)
+        );
+        assert_eq!(
+            part2(&something, &(), &Context::none())
+                .unwrap()
+                .answer
+                .to_string(),
+            expected(DAY.year, DAY.day, Part::Second)
+        );
     }
 }
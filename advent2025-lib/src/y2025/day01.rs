@@ -0,0 +1,265 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::{
+    Context, Day, DayCalc, Example, ParseError, ParseResult, PartOutput, SolveError, SolveResult,
+    Variants,
+    generate::{Generator, Rng},
+    parser,
+    visualize::Frame,
+};
+
+#[derive(Debug)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+#[derive(Debug)]
+pub struct Rotation {
+    direction: Direction,
+    steps: usize,
+}
+
+impl Rotation {
+    pub(crate) fn val(&self) -> Result<isize, SolveError> {
+        let sign: isize = match self.direction {
+            Direction::Left => -1,
+            Direction::Right => 1,
+        };
+        isize::try_from(self.steps)
+            .ok()
+            .and_then(|steps| sign.checked_mul(steps))
+            .ok_or(SolveError::Overflow)
+    }
+}
+
+impl FromStr for Rotation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let direction = match s.chars().next() {
+            Some('L') => Ok(Direction::Left),
+            Some('R') => Ok(Direction::Right),
+            Some(c) => Err(ParseError::invalid(format!("invalid direction {c}")).at_column(1, 1)),
+            None => Err(ParseError::empty()),
+        }
+        .map_err(|e| e.expected("`L` or `R`"))?;
+        let steps = parser::parse_token(&s[1..], 2, "a number of steps")?;
+        Ok(Self { direction, steps })
+    }
+}
+
+impl Display for Rotation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let direction = match self.direction {
+            Direction::Left => 'L',
+            Direction::Right => 'R',
+        };
+        write!(f, "{direction}{}", self.steps)
+    }
+}
+
+#[derive(Debug)]
+pub struct Rotations(Vec<Rotation>);
+
+impl Rotations {
+    pub fn iter(&self) -> impl IntoIterator<Item = &Rotation> {
+        &self.0
+    }
+
+    fn sequence(&self, start: usize, ctx: &Context) -> Result<Vec<usize>, SolveError> {
+        let mut sequence = vec![start];
+        let mut last_dial = isize::try_from(start).map_err(|_| SolveError::Overflow)?;
+        let total = self.0.len() as u64;
+        for (done, rotation) in self.0.iter().enumerate() {
+            ctx.checkpoint(done as u64, total)?;
+            let next_dial = last_dial
+                .checked_add(rotation.val()?)
+                .ok_or(SolveError::Overflow)?
+                .rem_euclid(100);
+            sequence.push(next_dial.unsigned_abs());
+            last_dial = next_dial;
+        }
+        Ok(sequence)
+    }
+}
+
+impl Display for Rotations {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for rotation in self.iter() {
+            writeln!(f, "{rotation}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Rotations {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rotations = parser::parse_lines(s, |line| line.trim().parse())?;
+        Ok(Self(rotations))
+    }
+}
+
+pub fn parse(input: &str) -> ParseResult<Rotations> {
+    Rotations::from_str(input)
+}
+
+/// The dial as a row of its 100 positions, with `|` pointing at `dial` and `0` marking zero.
+fn dial_frame(step: usize, rotation: Option<&Rotation>, dial: usize) -> Frame {
+    let row = (0..100)
+        .map(|pos| match pos {
+            pos if pos == dial => '|',
+            0 => '0',
+            _ => '.',
+        })
+        .collect();
+    let caption = match rotation {
+        Some(rotation) => format!("Rotation {step}: {rotation} to {dial}"),
+        None => format!("Starting at {dial}"),
+    };
+    Frame::new(caption, vec![row])
+}
+
+pub fn part1(rotations: &Rotations, _params: &(), ctx: &Context) -> SolveResult<usize> {
+    let sequence = rotations.sequence(50, ctx)?;
+    if ctx.is_visualized() {
+        ctx.frame(|| dial_frame(0, None, sequence[0]));
+        let turns = rotations.iter().into_iter().zip(&sequence[1..]);
+        for (step, (rotation, dial)) in turns.enumerate() {
+            ctx.frame(|| dial_frame(step + 1, Some(rotation), *dial));
+        }
+    }
+    let zeros = sequence.iter().filter(|&dial| *dial == 0).count();
+    Ok(PartOutput { answer: zeros })
+}
+
+pub fn part2(rotations: &Rotations, _params: &(), ctx: &Context) -> SolveResult<usize> {
+    let mut zero_count = 0usize;
+    let mut last_dial = 50isize;
+    let total = rotations.0.len() as u64;
+    for (done, rotation) in rotations.0.iter().enumerate() {
+        ctx.checkpoint(done as u64, total)?;
+        let next_dial = last_dial
+            .checked_add(rotation.val()?)
+            .ok_or(SolveError::Overflow)?;
+        // Zero counting
+        {
+            let zeros = if last_dial == 0 {
+                (next_dial / 100).unsigned_abs()
+            } else if next_dial <= 0 {
+                next_dial
+                    .checked_sub(1)
+                    .ok_or(SolveError::Overflow)?
+                    .div_euclid(100)
+                    .unsigned_abs()
+            } else {
+                next_dial.div_euclid(100).unsigned_abs()
+            };
+            zero_count = zero_count.checked_add(zeros).ok_or(SolveError::Overflow)?;
+        }
+        log::debug!(
+            "{} by {} to {} with {} zeros",
+            last_dial,
+            rotation.val()?,
+            next_dial.rem_euclid(100),
+            zero_count
+        );
+        last_dial = next_dial.rem_euclid(100);
+    }
+    Ok(PartOutput { answer: zero_count })
+}
+
+/// `size` rotations of up to 999 steps, like the real input's.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    (0..size)
+        .map(|_| {
+            let direction = if rng.chance(0.5) { 'L' } else { 'R' };
+            format!("{direction}{}\n", rng.range(1..=999))
+        })
+        .collect()
+}
+
+pub const DAY: Day<Rotations, usize, usize> = Day {
+    year: 2025,
+    day: 1,
+    title: "Secret Entrance",
+    display: (
+        "The password to open the door is maybe {answer}.",
+        "The password to open the door is actually {answer}.",
+    ),
+    calc: DayCalc {
+        parse,
+        part1,
+        part2,
+    },
+    variants: Variants::none(),
+    generator: Some(Generator {
+        unit: "rotations",
+        write: generate,
+    }),
+    examples: &[Example::both(
+        include_str!("../../../examples/2025/day01.txt"),
+        "3",
+        "6",
+    )],
+};
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use super::*;
+    use crate::DayTrait as _;
+    use crate::Part;
+    use crate::Printable as _;
+    use crate::answers::expected;
+
+    #[test]
+    fn test_example_part1() {
+        let rotations = parse(DAY.examples().first()).unwrap();
+        for rotation in rotations.iter() {
+            log::info!("{:?} has val {}", rotation, rotation.val().unwrap());
+        }
+        log::info!(
+            "Sequence: {:?}",
+            rotations.sequence(50, &Context::none()).unwrap()
+        );
+        let result = part1(&rotations, &(), &Context::none()).unwrap();
+        assert_eq!(result.unwrap(), 3);
+    }
+
+    #[test]
+    fn test_example_part2() {
+        let something = parse(DAY.examples().second()).unwrap();
+        let result = part2(&something, &(), &Context::none()).unwrap();
+        assert_eq!(result.unwrap(), 6);
+    }
+
+    #[test]
+    fn test_generated_input_solves() {
+        let rotations = parse(&generate(1000, &mut Rng::new(0))).unwrap();
+        part1(&rotations, &(), &Context::none()).unwrap();
+        part2(&rotations, &(), &Context::none()).unwrap();
+    }
+
+    #[test]
+    fn test_main() {
+        let something = parse(&DAY.input().unwrap()).unwrap();
+        assert_eq!(
+            part1(&something, &(), &Context::none())
+                .unwrap()
+                .answer
+                .to_string(),
+            expected(DAY.year, DAY.day, Part::First)
+        );
+        assert_eq!(
+            part2(&something, &(), &Context::none())
+                .unwrap()
+                .answer
+                .to_string(),
+            expected(DAY.year, DAY.day, Part::Second)
+        );
+    }
+}
@@ -0,0 +1,360 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::{
+    Context, Day, DayCalc, Example, ParseError, ParseResult, PartOutput, SolveError, SolveResult,
+    Variant, Variants,
+    generate::{Generator, Rng},
+    parser,
+};
+
+/// The most digits an ID can have.
+const MAX_DIGITS: u32 = usize::MAX.ilog10() + 1;
+
+#[derive(Debug)]
+pub struct Range {
+    start: usize,
+    end: usize,
+}
+
+impl Range {
+    fn ints_chars(&self) -> impl Iterator<Item = (usize, Vec<char>)> {
+        (self.start..=self.end).map(|i| (i, i.to_string().chars().collect()))
+    }
+
+    /// The IDs in the range of `digits` digits that are a block repeated `repeats` times, such
+    /// as 123123 for six digits repeated twice, in increasing order and each with its block.
+    fn repeated(&self, digits: u32, repeats: u32) -> impl Iterator<Item = (usize, usize)> {
+        let block = digits / repeats;
+        // The ID is the block times the pattern, 1001 in the example above.
+        let pattern = (0..repeats).try_fold(0usize, |pattern, _| {
+            pattern
+                .checked_mul(10usize.checked_pow(block)?)?
+                .checked_add(1)
+        });
+        let ids = pattern
+            .zip(10usize.checked_pow(block - 1))
+            .map(|(pattern, smallest)| {
+                let largest = smallest.saturating_mul(10) - 1;
+                let first = self.start.div_ceil(pattern).max(smallest);
+                let last = (self.end / pattern).min(largest);
+                (first..=last).map(move |block| (block, block * pattern))
+            });
+        ids.into_iter().flatten()
+    }
+
+    /// The number of IDs in the range.
+    fn len(&self) -> u64 {
+        self.end
+            .checked_sub(self.start)
+            .map_or(0, |span| span as u64 + 1)
+    }
+}
+
+impl Display for Range {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+#[derive(Debug)]
+pub struct Ranges(Vec<Range>);
+
+impl Ranges {
+    fn iter(&self) -> std::slice::Iter<'_, Range> {
+        self.0.iter()
+    }
+
+    /// The number of IDs in all the ranges.
+    fn len(&self) -> u64 {
+        self.iter().map(Range::len).fold(0, u64::saturating_add)
+    }
+}
+
+impl FromStr for Ranges {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines = parser::parse_lines(s.trim_end(), |line| {
+            parser::split_columns(line, ',')
+                .map(|(column, range)| -> Result<Range, ParseError> {
+                    let (start, end) = range.split_once('-').ok_or_else(|| {
+                        ParseError::invalid(format!("invalid range {range:?}"))
+                            .at_column(column, range.chars().count())
+                            .expected("`-`")
+                    })?;
+                    Ok(Range {
+                        start: parser::parse_token(start, column, "a range start")?,
+                        end: parser::parse_token(
+                            end,
+                            column + start.chars().count() + 1,
+                            "a range end",
+                        )?,
+                    })
+                })
+                .collect::<Result<Vec<Range>, ParseError>>()
+        })?;
+        Ok(Ranges(lines.into_iter().flatten().collect()))
+    }
+}
+
+impl Display for Ranges {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ranges: Vec<String> = self.iter().map(Range::to_string).collect();
+        writeln!(f, "{}", ranges.join(","))
+    }
+}
+
+pub fn parse(input: &str) -> ParseResult<Ranges> {
+    Ranges::from_str(input)
+}
+
+/// Sums the IDs in the ranges that are a block repeated any of `repeats(digits)` times, for
+/// those of each number of digits, and whose block is `counted`.
+fn sum_repeated(
+    ranges: &Ranges,
+    ctx: &Context,
+    mut repeats: impl FnMut(u32) -> Vec<u32>,
+    mut counted: impl FnMut(usize) -> bool,
+) -> Result<usize, SolveError> {
+    ranges.iter().try_fold(0usize, |sum, range| {
+        ctx.check()?;
+        (1..=MAX_DIGITS)
+            .flat_map(|digits| {
+                repeats(digits)
+                    .into_iter()
+                    .flat_map(move |repeats| range.repeated(digits, repeats))
+            })
+            .filter(|&(block, _)| counted(block))
+            .try_fold(sum, |sum, (_, id)| {
+                sum.checked_add(id).ok_or(SolveError::Overflow)
+            })
+    })
+}
+
+pub fn part1(ranges: &Ranges, _params: &(), ctx: &Context) -> SolveResult<usize> {
+    let halves = |digits: u32| {
+        if digits.is_multiple_of(2) {
+            vec![2]
+        } else {
+            vec![]
+        }
+    };
+    let retval = sum_repeated(ranges, ctx, halves, |_| true)?;
+    Ok(PartOutput { answer: retval })
+}
+
+/// Checks every ID in the ranges, which takes as long as the ranges are wide.
+pub fn part1_brute_force(ranges: &Ranges, _params: &(), ctx: &Context) -> SolveResult<usize> {
+    let total = ranges.len();
+    let retval = ranges
+        .iter()
+        .flat_map(Range::ints_chars)
+        .enumerate()
+        .try_fold(0usize, |sum, (done, (int, seq))| {
+            ctx.checkpoint(done as u64, total)?;
+            let invalid = seq.len().is_multiple_of(2) && {
+                let (first, second) = seq.split_at(seq.len() / 2);
+                first == second
+            };
+            if invalid {
+                sum.checked_add(int).ok_or(SolveError::Overflow)
+            } else {
+                Ok(sum)
+            }
+        })?;
+    Ok(PartOutput { answer: retval })
+}
+
+fn find_divisors(n: usize) -> Vec<usize> {
+    let mut divisors = Vec::new();
+    let sqrt_n = (n as f64).sqrt() as usize;
+    for i in 1..=sqrt_n {
+        if n.is_multiple_of(i) {
+            divisors.push(i);
+            if i != n / i {
+                divisors.push(n / i);
+            }
+        }
+    }
+    divisors.sort_unstable();
+    divisors
+}
+
+/// Whether `seq` is a block repeated at least twice.
+fn is_repeated<T: PartialEq>(seq: &[T]) -> bool {
+    find_divisors(seq.len())
+        .into_iter()
+        .rev()
+        .skip(1) // skip the number itself
+        .any(|div| seq.chunks(div).all(|c| c == &seq[..div]))
+}
+
+pub fn part2(ranges: &Ranges, _params: &(), ctx: &Context) -> SolveResult<usize> {
+    let repeats = |digits: u32| {
+        find_divisors(digits as usize)
+            .into_iter()
+            .skip(1) // a single repeat is the ID itself
+            .map(|repeats| repeats as u32)
+            .collect()
+    };
+    // An ID such as 111111 is a repeat of 1, 11 and 111, so only count it by its shortest block,
+    // the one that is not itself repeated.
+    let retval = sum_repeated(ranges, ctx, repeats, |block| {
+        !is_repeated(block.to_string().as_bytes())
+    })?;
+    Ok(PartOutput { answer: retval })
+}
+
+/// Checks every ID in the ranges, which takes as long as the ranges are wide.
+pub fn part2_brute_force(ranges: &Ranges, _params: &(), ctx: &Context) -> SolveResult<usize> {
+    let total = ranges.len();
+    let retval = ranges
+        .iter()
+        .flat_map(Range::ints_chars)
+        .enumerate()
+        .try_fold(0usize, |sum, (done, (int, seq))| {
+            ctx.checkpoint(done as u64, total)?;
+            if is_repeated(&seq) {
+                sum.checked_add(int).ok_or(SolveError::Overflow)
+            } else {
+                Ok(sum)
+            }
+        })?;
+    Ok(PartOutput { answer: retval })
+}
+
+/// `size` ranges of IDs of up to ten digits, each spanning up to 250000 IDs like the real
+/// input's.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let ranges: Vec<String> = (0..size)
+        .map(|_| {
+            let digits = rng.range(1..=10) as u32;
+            let start = rng.range(10usize.pow(digits - 1)..=10usize.pow(digits) - 1);
+            let end = start + rng.range(0..=start.min(250_000));
+            format!("{start}-{end}")
+        })
+        .collect();
+    format!("{}\n", ranges.join(","))
+}
+
+pub const DAY: Day<Ranges, usize, usize> = Day {
+    year: 2025,
+    day: 2,
+    title: "Gift Shop",
+    display: (
+        "The sum of all the invalid IDs using old rules is: {answer}",
+        "The sum of all the invalid IDs using new rules is: {answer}",
+    ),
+    calc: DayCalc {
+        parse,
+        part1,
+        part2,
+    },
+    variants: Variants {
+        part1: &[Variant {
+            name: "brute_force",
+            solve: part1_brute_force,
+        }],
+        part2: &[Variant {
+            name: "brute_force",
+            solve: part2_brute_force,
+        }],
+    },
+    generator: Some(Generator {
+        unit: "ranges",
+        write: generate,
+    }),
+    examples: &[Example::both(
+        include_str!("../../../examples/2025/day02.txt"),
+        "1227775554",
+        "4174379265",
+    )],
+};
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use super::*;
+    use crate::DayTrait as _;
+    use crate::Part;
+    use crate::Printable as _;
+    use crate::answers::expected;
+
+    #[test]
+    fn test_find_divisors_12() {
+        let divisors = find_divisors(12);
+        assert_eq!(divisors, vec![1, 2, 3, 4, 6, 12]);
+    }
+
+    #[test]
+    fn test_find_divisors_120() {
+        let divisors = find_divisors(120);
+        assert_eq!(
+            divisors,
+            vec![1, 2, 3, 4, 5, 6, 8, 10, 12, 15, 20, 24, 30, 40, 60, 120]
+        );
+    }
+
+    #[test]
+    fn test_parse_error_locates_line() {
+        let error = parse("11-22,95-115\n998-1012,L68\n").unwrap_err();
+        assert_eq!(error.line(), Some(2));
+        assert_eq!(error.column(), Some(10));
+        assert_eq!(error.snippet(), Some("998-1012,L68"));
+    }
+
+    #[test]
+    fn test_example_part1() {
+        let ranges = parse(DAY.examples().first()).unwrap();
+        let result = part1(&ranges, &(), &Context::none()).unwrap();
+        assert_eq!(result.unwrap(), 1227775554);
+    }
+
+    #[test]
+    fn test_example_part2() {
+        let ranges = parse(DAY.examples().second()).unwrap();
+        let result = part2(&ranges, &(), &Context::none()).unwrap();
+        assert_eq!(result.unwrap(), 4174379265);
+    }
+
+    #[test]
+    fn test_generated_input_solves() {
+        let ranges = parse(&generate(1000, &mut Rng::new(0))).unwrap();
+        part1(&ranges, &(), &Context::none()).unwrap();
+        part2(&ranges, &(), &Context::none()).unwrap();
+    }
+
+    #[test]
+    fn test_brute_force_agrees() {
+        let ranges = parse(&generate(10, &mut Rng::new(0))).unwrap();
+        let ctx = Context::none();
+        assert_eq!(
+            part1(&ranges, &(), &ctx).unwrap().answer,
+            part1_brute_force(&ranges, &(), &ctx).unwrap().answer
+        );
+        assert_eq!(
+            part2(&ranges, &(), &ctx).unwrap().answer,
+            part2_brute_force(&ranges, &(), &ctx).unwrap().answer
+        );
+    }
+
+    #[test]
+    fn test_main() {
+        let ranges = parse(&DAY.input().unwrap()).unwrap();
+        assert_eq!(
+            part1(&ranges, &(), &Context::none())
+                .unwrap()
+                .answer
+                .to_string(),
+            expected(DAY.year, DAY.day, Part::First)
+        );
+        assert_eq!(
+            part2(&ranges, &(), &Context::none())
+                .unwrap()
+                .answer
+                .to_string(),
+            expected(DAY.year, DAY.day, Part::Second)
+        );
+    }
+}
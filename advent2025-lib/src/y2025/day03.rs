@@ -0,0 +1,197 @@
+use crate::{
+    Context, Day, DayCalc, Example, ParseError, ParseResult, PartOutput, SolveError, SolveResult,
+    Variants, parser,
+};
+use std::{fmt::Display, str::FromStr};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Joltage(usize);
+
+impl Display for Joltage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JoltageRating(u8);
+
+#[derive(Debug, Clone)]
+pub struct BatteryBank(Vec<JoltageRating>);
+
+impl BatteryBank {
+    fn joltage(&self) -> Result<Joltage, SolveError> {
+        self.0
+            .iter()
+            .rev()
+            .enumerate()
+            .try_fold(0usize, |acc, (rating_index, joltage_rating)| {
+                u32::try_from(rating_index)
+                    .ok()
+                    .and_then(|exp| 10usize.checked_pow(exp))
+                    .and_then(|scale| usize::from(joltage_rating.0).checked_mul(scale))
+                    .and_then(|value| acc.checked_add(value))
+            })
+            .map(Joltage)
+            .ok_or(SolveError::Overflow)
+    }
+}
+
+impl FromStr for BatteryBank {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(BatteryBank(
+            s.chars()
+                .enumerate()
+                .map(|(idx, c)| {
+                    parser::parse_token(&c.to_string(), idx + 1, "a joltage digit")
+                        .map(JoltageRating)
+                })
+                .collect::<Result<Vec<JoltageRating>, _>>()?,
+        ))
+    }
+}
+
+impl Display for BatteryBank {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for rating in &self.0 {
+            write!(f, "{}", rating.0)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BatteryBanks(Vec<BatteryBank>);
+
+impl FromStr for BatteryBanks {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(BatteryBanks(parser::parse_lines(s, BatteryBank::from_str)?))
+    }
+}
+
+impl Display for BatteryBanks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for bank in &self.0 {
+            writeln!(f, "{bank}")?;
+        }
+        Ok(())
+    }
+}
+
+pub fn parse(input: &str) -> ParseResult<BatteryBanks> {
+    BatteryBanks::from_str(input)
+}
+
+fn largest_joltage(
+    banks: &BatteryBanks,
+    battery_count: usize,
+    ctx: &Context,
+) -> Result<Joltage, SolveError> {
+    banks.0.iter().try_fold(Joltage(0), |total, bank| {
+        ctx.check()?;
+        let mut max_bank = BatteryBank(
+            vec![0; battery_count]
+                .into_iter()
+                .map(JoltageRating)
+                .collect(),
+        );
+        for joltage_window in bank.0.windows(battery_count) {
+            let mut new_set = false;
+            for (idx, joltage) in joltage_window.iter().enumerate() {
+                if new_set {
+                    max_bank.0[idx] = *joltage;
+                } else if joltage.0 > max_bank.0[idx].0 {
+                    max_bank.0[idx] = *joltage;
+                    new_set = true;
+                }
+            }
+        }
+        let bank_joltage: Joltage = max_bank.joltage()?;
+        log::info!("Bank Joltage: {bank_joltage:?}");
+        total
+            .0
+            .checked_add(bank_joltage.0)
+            .map(Joltage)
+            .ok_or(SolveError::Overflow)
+    })
+}
+
+pub fn part1(banks: &BatteryBanks, _params: &(), ctx: &Context) -> SolveResult<Joltage> {
+    Ok(PartOutput {
+        answer: largest_joltage(banks, 2, ctx)?,
+    })
+}
+
+pub fn part2(banks: &BatteryBanks, _params: &(), ctx: &Context) -> SolveResult<Joltage> {
+    Ok(PartOutput {
+        answer: largest_joltage(banks, 12, ctx)?,
+    })
+}
+
+pub const DAY: Day<BatteryBanks, Joltage, Joltage> = Day {
+    year: 2025,
+    day: 3,
+    title: "Lobby",
+    display: (
+        "The total output joltage is: {answer}",
+        "The new output joltage is: {answer}",
+    ),
+    calc: DayCalc {
+        parse,
+        part1,
+        part2,
+    },
+    variants: Variants::none(),
+    generator: None,
+    examples: &[Example::both(
+        include_str!("../../../examples/2025/day03.txt"),
+        "357",
+        "3121910778619",
+    )],
+};
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use super::*;
+    use crate::DayTrait as _;
+    use crate::Part;
+    use crate::Printable as _;
+    use crate::answers::expected;
+
+    #[test]
+    fn test_example_part1() {
+        let banks = parse(DAY.examples().first()).unwrap();
+        let result = part1(&banks, &(), &Context::none()).unwrap();
+        assert_eq!(result.unwrap(), Joltage(357));
+    }
+
+    #[test]
+    fn test_example_part2() {
+        let banks = parse(DAY.examples().second()).unwrap();
+        let result = part2(&banks, &(), &Context::none()).unwrap();
+        assert_eq!(result.unwrap(), Joltage(3121910778619));
+    }
+
+    #[test]
+    fn test_main() {
+        let something = parse(&DAY.input().unwrap()).unwrap();
+        assert_eq!(
+            part1(&something, &(), &Context::none())
+                .unwrap()
+                .answer
+                .to_string(),
+            expected(DAY.year, DAY.day, Part::First)
+        );
+        assert_eq!(
+            part2(&something, &(), &Context::none())
+                .unwrap()
+                .answer
+                .to_string(),
+            expected(DAY.year, DAY.day, Part::Second)
+        );
+    }
+}
@@ -0,0 +1,316 @@
+use std::{collections::HashSet, fmt::Display, str::FromStr};
+
+use crate::{
+    Context, Day, DayCalc, Example, ParseError, PartOutput, SolveError, SolveResult, Variants,
+    generate::{Generator, Rng},
+    parser,
+    visualize::Frame,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RowColPos {
+    row: usize,
+    col: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum RollPaper {
+    Absent,
+    Present,
+}
+
+#[derive(Debug, Clone)]
+pub struct Line(Vec<RollPaper>);
+
+impl FromStr for Line {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Line(
+            s.chars()
+                .enumerate()
+                .map(|(idx, c)| match c {
+                    '.' => Ok(RollPaper::Absent),
+                    '@' => Ok(RollPaper::Present),
+                    _ => Err(ParseError::invalid(format!("invalid char {c:?}"))
+                        .at_column(idx + 1, 1)
+                        .expected("`.` or `@`")),
+                })
+                .collect::<Result<_, _>>()?,
+        ))
+    }
+}
+
+impl Display for Line {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for roll_paper in &self.0 {
+            match roll_paper {
+                RollPaper::Absent => write!(f, ".")?,
+                RollPaper::Present => write!(f, "@")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Diagram {
+    lines: Vec<Line>,
+}
+
+impl Diagram {
+    fn width(&self) -> usize {
+        self.lines.first().map_or(0, |line| line.0.len())
+    }
+    fn get(&self, pos: &RowColPos) -> RollPaper {
+        self.lines[pos.row].0[pos.col]
+    }
+    /// Every position of the diagram, row by row.
+    fn positions(&self) -> impl ExactSizeIterator<Item = RowColPos> {
+        let width = self.width();
+        (0..self.lines.len() * width).map(move |idx| RowColPos {
+            row: idx / width,
+            col: idx % width,
+        })
+    }
+    fn adjacent_3_3(&self, pos: &RowColPos) -> [[Option<RollPaper>; 3]; 3] {
+        let mut result = [[None; 3]; 3];
+        for (row_idx, row_offset) in (-1isize..=1isize).enumerate() {
+            for (col_idx, col_offset) in (-1isize..=1isize).enumerate() {
+                let (Some(row), Some(col)) = (
+                    pos.row.checked_add_signed(row_offset),
+                    pos.col.checked_add_signed(col_offset),
+                ) else {
+                    continue;
+                };
+                if row >= self.lines.len() || col >= self.width() {
+                    continue;
+                }
+                result[row_idx][col_idx] = Some(self.lines[row].0[col]);
+            }
+        }
+        result
+    }
+    /// The current rolls as rows of `@` and `.`, with those in `removed` drawn as `x`.
+    fn frame(&self, caption: String, removed: &[RowColPos]) -> Frame {
+        let removed: HashSet<&RowColPos> = removed.iter().collect();
+        let width = self.width();
+        let rows = (0..self.lines.len())
+            .map(|row| {
+                (0..width)
+                    .map(|col| {
+                        let pos = RowColPos { row, col };
+                        match self.get(&pos) {
+                            _ if removed.contains(&pos) => 'x',
+                            RollPaper::Present => '@',
+                            RollPaper::Absent => '.',
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        Frame::new(caption, rows)
+    }
+    /// The positions around `pos` within the diagram.
+    fn neighbours(&self, pos: &RowColPos) -> impl Iterator<Item = RowColPos> {
+        let (rows, cols) = (self.lines.len(), self.width());
+        let pos = *pos;
+        (-1isize..=1)
+            .flat_map(|row_offset| (-1isize..=1).map(move |col_offset| (row_offset, col_offset)))
+            .filter(|&offsets| offsets != (0, 0))
+            .filter_map(move |(row_offset, col_offset)| {
+                let row = pos.row.checked_add_signed(row_offset)?;
+                let col = pos.col.checked_add_signed(col_offset)?;
+                (row < rows && col < cols).then_some(RowColPos { row, col })
+            })
+    }
+    /// The rolls among `candidates` with fewer than four rolls around them, which a forklift
+    /// can reach.
+    fn accessible(
+        &self,
+        candidates: impl ExactSizeIterator<Item = RowColPos>,
+        ctx: &Context,
+    ) -> Result<Vec<RowColPos>, SolveError> {
+        let total = candidates.len() as u64;
+        let mut accessible = Vec::new();
+        for (done, pos) in candidates.enumerate() {
+            ctx.checkpoint(done as u64, total)?;
+            if self.get(&pos) == RollPaper::Present
+                && self
+                    .adjacent_8(&pos)
+                    .into_iter()
+                    .filter(|&adjacent_val| adjacent_val == Some(RollPaper::Present))
+                    .count()
+                    < 4
+            {
+                log::debug!("Can remove: {:?}", pos);
+                accessible.push(pos);
+            }
+        }
+        Ok(accessible)
+    }
+    fn adjacent_8(&self, pos: &RowColPos) -> [Option<RollPaper>; 8] {
+        let adjacents = self.adjacent_3_3(pos);
+        [
+            adjacents[0][0],
+            adjacents[0][1],
+            adjacents[0][2],
+            adjacents[1][0],
+            adjacents[1][2],
+            adjacents[2][0],
+            adjacents[2][1],
+            adjacents[2][2],
+        ]
+    }
+}
+
+impl FromStr for Diagram {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines = parser::parse_lines(s, Line::from_str)?;
+        let diagram = Diagram { lines };
+        log::debug!("Parsed diagram:\n{diagram}");
+        Ok(diagram)
+    }
+}
+
+impl Display for Diagram {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for line in &self.lines {
+            writeln!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+pub fn part1(diagram: &Diagram, _params: &(), ctx: &Context) -> SolveResult<usize> {
+    Ok(PartOutput {
+        answer: diagram.accessible(diagram.positions(), ctx)?.len(),
+    })
+}
+
+pub fn part2(diagram: &Diagram, _params: &(), ctx: &Context) -> SolveResult<usize> {
+    ctx.check()?;
+    let mut diagram = diagram.clone();
+    let mut total_rolls_removed = 0;
+    let mut loop_rolls_removed = usize::MAX;
+    // Only a roll next to one just removed can have become accessible.
+    let mut candidates: Vec<RowColPos> = diagram.positions().collect();
+    while loop_rolls_removed > 0 {
+        let removed_positions = diagram.accessible(candidates.iter().copied(), ctx)?;
+        loop_rolls_removed = removed_positions.len();
+        total_rolls_removed += loop_rolls_removed;
+        ctx.frame(|| {
+            diagram.frame(
+                format!("Removing {loop_rolls_removed} rolls, {total_rolls_removed} in all"),
+                &removed_positions,
+            )
+        });
+        candidates = removed_positions
+            .iter()
+            .flat_map(|pos| diagram.neighbours(pos))
+            .collect();
+        candidates.sort_unstable();
+        candidates.dedup();
+        for pos in removed_positions {
+            diagram.lines[pos.row].0[pos.col] = RollPaper::Absent;
+        }
+    }
+    Ok(PartOutput {
+        answer: total_rolls_removed,
+    })
+}
+
+/// A square grid of `size` rows, about two thirds of it rolls of paper like the real input's.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    (0..size)
+        .map(|_| {
+            let mut line: String = (0..size)
+                .map(|_| if rng.chance(0.65) { '@' } else { '.' })
+                .collect();
+            line.push('\n');
+            line
+        })
+        .collect()
+}
+
+pub const DAY: Day<Diagram, usize, usize> = Day {
+    year: 2025,
+    day: 4,
+    title: "Printing Department",
+    display: (
+        "{answer} rolls of paper can be accessed by a forklift.",
+        "{answer} rolls of paper in total can be removed by the Elves and their forklifts.",
+    ),
+    calc: DayCalc {
+        parse: Diagram::from_str,
+        part1,
+        part2,
+    },
+    variants: Variants::none(),
+    generator: Some(Generator {
+        unit: "rows",
+        write: generate,
+    }),
+    examples: &[Example::both(
+        include_str!("../../../examples/2025/day04.txt"),
+        "13",
+        "43",
+    )],
+};
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use super::*;
+    use crate::DayTrait as _;
+    use crate::Part;
+    use crate::Printable as _;
+    use crate::answers::expected;
+
+    #[test]
+    fn test_example_part1() {
+        let parse = DAY.calc.parse;
+        let diagram = parse(DAY.examples().first()).unwrap();
+        let result = part1(&diagram, &(), &Context::none()).unwrap();
+        assert_eq!(result.unwrap(), 13);
+    }
+
+    #[test]
+    fn test_example_part2() {
+        let parse = DAY.calc.parse;
+        let diagram = parse(DAY.examples().second()).unwrap();
+        let result = part2(&diagram, &(), &Context::none()).unwrap();
+        assert_eq!(result.unwrap(), 43);
+    }
+
+    #[test]
+    fn test_generated_input_solves() {
+        let parse = DAY.calc.parse;
+        let diagram = parse(&generate(300, &mut Rng::new(0))).unwrap();
+        part1(&diagram, &(), &Context::none()).unwrap();
+        part2(&diagram, &(), &Context::none()).unwrap();
+    }
+
+    #[test]
+    fn test_main() {
+        let parse = DAY.calc.parse;
+        let diagram = parse(&DAY.input().unwrap()).unwrap();
+        assert_eq!(
+            part1(&diagram, &(), &Context::none())
+                .unwrap()
+                .answer
+                .to_string(),
+            expected(DAY.year, DAY.day, Part::First)
+        );
+        assert_eq!(
+            part2(&diagram, &(), &Context::none())
+                .unwrap()
+                .answer
+                .to_string(),
+            expected(DAY.year, DAY.day, Part::Second)
+        );
+    }
+}
@@ -0,0 +1,316 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    ops::RangeInclusive,
+    str::FromStr,
+};
+
+use crate::{
+    Context, Day, DayCalc, Example, ParseError, PartOutput, SolveError, SolveResult, Variant,
+    Variants,
+    generate::{Generator, Rng},
+    parser,
+};
+
+type IngredientId = usize;
+
+#[derive(Debug, Clone)]
+pub struct Database {
+    fresh_ranges: Vec<RangeInclusive<IngredientId>>,
+    available: Vec<IngredientId>,
+}
+
+impl Database {
+    /// The lowest and highest ingredient IDs covered by any fresh range.
+    fn bounds(&self) -> Option<(&IngredientId, &IngredientId)> {
+        let total_min = self.fresh_ranges.iter().map(|range| range.start()).min()?;
+        let total_max = self.fresh_ranges.iter().map(|range| range.end()).max()?;
+        Some((total_min, total_max))
+    }
+}
+
+impl FromStr for Database {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        log::trace!("Parsing database:\n{s}");
+        let mut sections = parser::sections(s).into_iter();
+        let (first_line, range_lines) = sections
+            .next()
+            .ok_or_else(|| ParseError::empty().expected("fresh ingredient ID ranges"))?;
+        let fresh_ranges = parser::parse_lines_from(range_lines, first_line, |line| {
+            log::trace!("Parsing range line: {line}");
+            let (start, end) = line.split_once('-').ok_or_else(|| {
+                ParseError::invalid(format!("invalid range {line:?}"))
+                    .at_column(1, line.chars().count())
+                    .expected("`-`")
+            })?;
+            let end_column = start.chars().count() + 2;
+            let start: IngredientId = parser::parse_token(start, 1, "a range start")?;
+            let end: IngredientId = parser::parse_token(end, end_column, "a range end")?;
+            Ok(start..=end)
+        })?;
+        let (first_line, available_lines) = sections
+            .next()
+            .ok_or_else(|| ParseError::empty().expected("available ingredient IDs"))?;
+        let available = parser::parse_lines_from(available_lines, first_line, |line| {
+            parser::parse_token(line, 1, "an ingredient ID")
+        })?;
+        Ok(Database {
+            fresh_ranges,
+            available,
+        })
+    }
+}
+
+impl Display for Database {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Database:")?;
+        for range in self.fresh_ranges.iter() {
+            writeln!(f, "  {range:?}")?;
+        }
+        Ok(())
+    }
+}
+
+pub fn part1(db: &Database, _params: &(), ctx: &Context) -> SolveResult<usize> {
+    let total = db.available.len() as u64;
+    let mut fresh = 0;
+    for (done, ingredient) in db.available.iter().enumerate() {
+        ctx.checkpoint(done as u64, total)?;
+        if db
+            .fresh_ranges
+            .iter()
+            .any(|range| range.contains(ingredient))
+        {
+            fresh += 1;
+        }
+    }
+    Ok(PartOutput { answer: fresh })
+}
+
+pub fn part2_brute_force(db: &Database, _params: &(), ctx: &Context) -> SolveResult<usize> {
+    let mut fresh = HashSet::<IngredientId>::new();
+    let total = db
+        .fresh_ranges
+        .iter()
+        .map(|range| (range.end() + 1).saturating_sub(*range.start()) as u64)
+        .fold(0, u64::saturating_add);
+    let ids = db.fresh_ranges.iter().flat_map(|range| range.clone());
+    for (idx, ingredient_id) in ids.enumerate() {
+        ctx.checkpoint(idx as u64, total)?;
+        fresh.insert(ingredient_id);
+    }
+    Ok(PartOutput {
+        answer: fresh.len(),
+    })
+}
+
+pub fn part2_counter(db: &Database, _params: &(), ctx: &Context) -> SolveResult<usize> {
+    let Some((total_min, total_max)) = db.bounds() else {
+        return Ok(PartOutput { answer: 0 });
+    };
+
+    let mut starts = HashMap::<IngredientId, HashSet<usize>>::new();
+    let mut ends = HashMap::<IngredientId, HashSet<usize>>::new();
+    for (idx, range) in db.fresh_ranges.iter().enumerate() {
+        starts.entry(*range.start()).or_default().insert(idx);
+        ends.entry(*range.end()).or_default().insert(idx);
+    }
+
+    let mut counter = 0;
+    let mut active_range_indices = HashSet::<usize>::new();
+    let total = (total_max - total_min) as u64 + 1;
+    for ingredient_id in *total_min..=*total_max {
+        ctx.checkpoint((ingredient_id - total_min) as u64, total)?;
+        if let Some(indices) = starts.get(&ingredient_id) {
+            active_range_indices.extend(indices);
+        };
+        log::trace!("{}: {:?}", ingredient_id, active_range_indices);
+        if !active_range_indices.is_empty() {
+            counter += 1;
+        }
+        if let Some(indices) = ends.get(&ingredient_id) {
+            active_range_indices.retain(|idx| !indices.contains(idx));
+        }
+    }
+    Ok(PartOutput { answer: counter })
+}
+
+pub fn part2(db: &Database, _params: &(), ctx: &Context) -> SolveResult<usize> {
+    let Some((total_min, total_max)) = db.bounds() else {
+        return Ok(PartOutput { answer: 0 });
+    };
+
+    let mut counter = 0;
+    let mut ingredient_id = *total_min;
+    loop {
+        ctx.check()?;
+        if let Some(max_end) = db
+            .fresh_ranges
+            .iter()
+            .filter(|range| range.contains(&ingredient_id))
+            .map(|range| range.end())
+            .max()
+        {
+            let next_ingredient_id = max_end.checked_add(1).ok_or(SolveError::Overflow)?;
+            counter += next_ingredient_id
+                .checked_sub(ingredient_id)
+                .ok_or(SolveError::Overflow)?;
+            ingredient_id = next_ingredient_id;
+            if ingredient_id > *total_max {
+                break;
+            }
+        } else {
+            if let Some(next_range) = db
+                .fresh_ranges
+                .iter()
+                .filter(|range| range.start() > &ingredient_id)
+                .min_by_key(|range| range.start())
+            {
+                ingredient_id = *next_range.start();
+            } else {
+                break;
+            }
+        }
+    }
+
+    Ok(PartOutput { answer: counter })
+}
+
+/// `size` fresh ingredient ID ranges of IDs up to 15 digits, then five times as many available
+/// IDs, like the real input's.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    const MAX_ID: IngredientId = 999_999_999_999_999;
+    let ranges: String = (0..size)
+        .map(|_| {
+            let start = rng.range(1..=MAX_ID);
+            let end = start.saturating_add(rng.range(0..=1_000_000_000_000));
+            format!("{start}-{end}\n")
+        })
+        .collect();
+    let available: String = (0..size * 5)
+        .map(|_| format!("{}\n", rng.range(1..=MAX_ID)))
+        .collect();
+    format!("{ranges}\n{available}")
+}
+
+pub const DAY: Day<Database, usize, usize> = Day {
+    year: 2025,
+    day: 5,
+    title: "Cafeteria",
+    display: (
+        "{answer} available ingredient IDs are fresh.",
+        "{answer} ingredient IDs are considered to be fresh according to the fresh ingredient ID ranges",
+    ),
+    calc: DayCalc {
+        parse: Database::from_str,
+        part1,
+        part2,
+    },
+    variants: Variants {
+        part1: &[],
+        part2: &[
+            Variant {
+                name: "brute_force",
+                solve: part2_brute_force,
+            },
+            Variant {
+                name: "counter",
+                solve: part2_counter,
+            },
+        ],
+    },
+    generator: Some(Generator {
+        unit: "ranges",
+        write: generate,
+    }),
+    examples: &[Example::both(
+        include_str!("../../../examples/2025/day05.txt"),
+        "3",
+        "14",
+    )],
+};
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use super::*;
+    use crate::DayTrait as _;
+    use crate::Part;
+    use crate::Printable as _;
+    use crate::answers::expected;
+
+    #[test]
+    fn test_example_part1() {
+        let parse = DAY.calc.parse;
+        let db = parse(DAY.examples().first()).unwrap();
+        let result = part1(&db, &(), &Context::none()).unwrap();
+        assert_eq!(result.unwrap(), 3);
+    }
+
+    #[test]
+    fn test_example_part2_brute_force() {
+        let parse = DAY.calc.parse;
+        let db = parse(DAY.examples().second()).unwrap();
+        let result = part2_brute_force(&db, &(), &Context::none()).unwrap();
+        assert_eq!(result.unwrap(), 14);
+    }
+
+    #[test]
+    fn test_example_part2_counter() {
+        let parse = DAY.calc.parse;
+        let db = parse(DAY.examples().second()).unwrap();
+        let result = part2_counter(&db, &(), &Context::none()).unwrap();
+        assert_eq!(result.unwrap(), 14);
+    }
+
+    #[test]
+    fn test_example_part2() {
+        let parse = DAY.calc.parse;
+        let db = parse(DAY.examples().second()).unwrap();
+        let result = part2(&db, &(), &Context::none()).unwrap();
+        assert_eq!(result.unwrap(), 14);
+    }
+
+    #[test]
+    fn test_parse_error_position() {
+        let error = Database::from_str("3-5\n10-14\n\n1\n5x\n").unwrap_err();
+        assert_eq!(error.line(), Some(5));
+        assert_eq!(error.column(), Some(1));
+        assert_eq!(error.snippet(), Some("5x"));
+        assert_eq!(error.expected_token(), Some("an ingredient ID"));
+        let error = Database::from_str("3-5\n10+14\n\n1\n").unwrap_err();
+        assert_eq!(error.line(), Some(2));
+        assert_eq!(error.expected_token(), Some("`-`"));
+    }
+
+    #[test]
+    fn test_generated_input_solves() {
+        let parse = DAY.calc.parse;
+        let db = parse(&generate(500, &mut Rng::new(0))).unwrap();
+        part1(&db, &(), &Context::none()).unwrap();
+        part2(&db, &(), &Context::none()).unwrap();
+    }
+
+    #[test]
+    fn test_main() {
+        let parse = DAY.calc.parse;
+        let db = parse(&DAY.input().unwrap()).unwrap();
+        assert_eq!(
+            part1(&db, &(), &Context::none())
+                .unwrap()
+                .answer
+                .to_string(),
+            expected(DAY.year, DAY.day, Part::First)
+        );
+        assert_eq!(
+            part2(&db, &(), &Context::none())
+                .unwrap()
+                .answer
+                .to_string(),
+            expected(DAY.year, DAY.day, Part::Second)
+        );
+    }
+}
@@ -0,0 +1,354 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::{
+    Context, Day, DayCalc, Example, ParseError, ParseResult, PartOutput, SolveError, SolveResult,
+    Variants,
+    generate::{Generator, Rng},
+    parser,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operation {
+    Add,
+    Multiply,
+}
+
+impl Operation {
+    fn from_char(c: char) -> ParseResult<Self> {
+        match c {
+            '+' => Ok(Self::Add),
+            '*' => Ok(Self::Multiply),
+            _ => Err(ParseError::invalid(format!("invalid operation {c:?}"))
+                .at_column(1, 1)
+                .expected("`+` or `*`")),
+        }
+    }
+}
+
+impl FromStr for Operation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        let first = chars
+            .next()
+            .ok_or_else(|| ParseError::empty().expected("an operation"))?;
+        let op = Self::from_char(first)?;
+        if chars.next().is_some() {
+            return Err(ParseError::invalid(format!("invalid operation {s:?}"))
+                .at_column(2, s.chars().count() - 1)
+                .expected("a single operation character"));
+        }
+        Ok(op)
+    }
+}
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Operation::Add => write!(f, "+"),
+            Operation::Multiply => write!(f, "*"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Problem {
+    operands: Vec<usize>,
+    operation: Operation,
+}
+
+impl Problem {
+    fn solve(&self) -> Result<usize, SolveError> {
+        let mut operands = self.operands.iter().copied();
+        match self.operation {
+            Operation::Add => operands.try_fold(0usize, usize::checked_add),
+            Operation::Multiply => operands.try_fold(1usize, usize::checked_mul),
+        }
+        .ok_or(SolveError::Overflow)
+    }
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let operands: Vec<String> = self.operands.iter().map(usize::to_string).collect();
+        write!(f, "{}", operands.join(&format!(" {} ", self.operation)))
+    }
+}
+
+fn grand_total(problems: &[Problem], ctx: &Context) -> Result<usize, SolveError> {
+    let total = problems.len() as u64;
+    problems
+        .iter()
+        .enumerate()
+        .try_fold(0usize, |sum, (done, problem)| {
+            ctx.checkpoint(done as u64, total)?;
+            sum.checked_add(problem.solve()?)
+                .ok_or(SolveError::Overflow)
+        })
+}
+
+#[derive(Debug, Clone)]
+pub struct HomeworkPart1 {
+    problems: Vec<Problem>,
+}
+
+impl FromStr for HomeworkPart1 {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<&str> = s.lines().collect();
+        let (operation_line, operand_lines) = lines
+            .split_last()
+            .ok_or_else(|| ParseError::empty().expected("a line of operations"))?;
+        let operations: Vec<Operation> =
+            parser::parse_lines_from([*operation_line], operand_lines.len(), |line| {
+                parser::whitespace_columns(line)
+                    .map(|(column, op)| {
+                        Operation::from_str(op).map_err(|e| e.offset_column(column - 1))
+                    })
+                    .collect::<ParseResult<Vec<Operation>>>()
+            })?
+            .remove(0);
+        let operands_by_line: Vec<Vec<usize>> =
+            parser::parse_lines_from(operand_lines.iter().copied(), 0, |line| {
+                let operands = parser::whitespace_columns(line)
+                    .map(|(column, num)| parser::parse_token(num, column, "an operand"))
+                    .collect::<ParseResult<Vec<usize>>>()?;
+                if operands.len() != operations.len() {
+                    return Err(ParseError::invalid(format!(
+                        "found {} operands for {} operations",
+                        operands.len(),
+                        operations.len()
+                    ))
+                    .expected(format!("{} operands", operations.len())));
+                }
+                Ok(operands)
+            })?;
+        let operands_by_column: Vec<Vec<usize>> = (0usize..operations.len())
+            .map(|col_idx| operands_by_line.iter().map(|line| line[col_idx]).collect())
+            .collect();
+        Ok(HomeworkPart1 {
+            problems: operands_by_column
+                .iter()
+                .zip(operations.iter())
+                .map(|(operands, operation)| Problem {
+                    operands: operands.to_owned(),
+                    operation: operation.to_owned(),
+                })
+                .collect(),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct HomeworkPart2 {
+    problems: Vec<Problem>,
+}
+
+impl FromStr for HomeworkPart2 {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<&str> = s.lines().collect();
+        let rows_cols: Vec<Vec<char>> = lines.iter().map(|line| line.chars().collect()).collect();
+        let longest_row = rows_cols.iter().map(|row| row.len()).max().unwrap_or(0);
+        let cols_rows: Vec<(usize, Vec<char>)> = (0..longest_row)
+            .map(|col_idx| {
+                (
+                    col_idx,
+                    rows_cols
+                        .iter()
+                        .map(|line| line.get(col_idx).unwrap_or(&' '))
+                        .copied()
+                        .collect(),
+                )
+            })
+            .rev()
+            .collect();
+        let problems: Vec<Problem> = cols_rows
+            .split(|(_, line)| line.iter().all(|c| c.is_whitespace()))
+            .map(|chars: &[(usize, Vec<char>)]| {
+                let operands: Vec<usize> = chars
+                    .iter()
+                    .map(|(col_idx, line)| {
+                        let digits = line.iter().rev().skip(1).rev().collect::<String>();
+                        parser::parse_token(digits.trim_ascii(), col_idx + 1, "a vertical operand")
+                    })
+                    .collect::<Result<_, _>>()?;
+                let (col_idx, operation) = chars
+                    .last()
+                    .and_then(|(col_idx, line)| Some((col_idx, *line.last()?)))
+                    .ok_or_else(|| ParseError::empty().expected("a problem"))?;
+                let operation: Operation = Operation::from_char(operation).map_err(|e| {
+                    e.offset_column(*col_idx)
+                        .at_line(lines.len(), lines.last().unwrap_or(&""))
+                })?;
+                Ok(Problem {
+                    operands,
+                    operation,
+                })
+            })
+            .collect::<ParseResult<Vec<Problem>>>()?;
+        Ok(Self { problems })
+    }
+}
+
+/// The worksheet, read both row-wise and column-wise.
+#[derive(Debug, Clone)]
+pub struct Worksheet {
+    row_wise: HomeworkPart1,
+    column_wise: HomeworkPart2,
+}
+
+impl Display for Worksheet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Row-wise:")?;
+        for problem in &self.row_wise.problems {
+            writeln!(f, "  {problem}")?;
+        }
+        writeln!(f, "Column-wise:")?;
+        for problem in &self.column_wise.problems {
+            writeln!(f, "  {problem}")?;
+        }
+        Ok(())
+    }
+}
+
+pub fn parse(s: &str) -> ParseResult<Worksheet> {
+    Ok(Worksheet {
+        row_wise: s.parse()?,
+        column_wise: s.parse()?,
+    })
+}
+
+pub fn part1(worksheet: &Worksheet, _params: &(), ctx: &Context) -> SolveResult<usize> {
+    Ok(PartOutput {
+        answer: grand_total(&worksheet.row_wise.problems, ctx)?,
+    })
+}
+
+pub fn part2(worksheet: &Worksheet, _params: &(), ctx: &Context) -> SolveResult<usize> {
+    Ok(PartOutput {
+        answer: grand_total(&worksheet.column_wise.problems, ctx)?,
+    })
+}
+
+/// A worksheet of `size` problems, each of four operands of up to four digits aligned left or
+/// right within its columns, like the real input's.
+///
+/// The operands of a problem are ordered by length, either way, so that no column has a gap
+/// between its digits.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    const OPERANDS: usize = 4;
+    let mut rows = vec![String::new(); OPERANDS + 1];
+    for problem in 0..size {
+        if problem > 0 {
+            rows.iter_mut().for_each(|row| row.push(' '));
+        }
+        let mut operands: Vec<String> = (0..OPERANDS)
+            .map(|_| {
+                let digits = rng.range(1..=4) as u32;
+                rng.range(10usize.pow(digits - 1)..=10usize.pow(digits) - 1)
+                    .to_string()
+            })
+            .collect();
+        operands.sort_by_key(String::len);
+        if rng.chance(0.5) {
+            operands.reverse();
+        }
+        let width = operands.iter().map(String::len).max().unwrap_or(1);
+        let left_aligned = rng.chance(0.5);
+        for (row, operand) in rows.iter_mut().zip(&operands) {
+            *row += &if left_aligned {
+                format!("{operand:<width$}")
+            } else {
+                format!("{operand:>width$}")
+            };
+        }
+        let operation = if rng.chance(0.5) { '+' } else { '*' };
+        rows[OPERANDS] += &format!("{operation:<width$}");
+    }
+    rows.iter()
+        .map(|row| format!("{}\n", row.trim_end()))
+        .collect()
+}
+
+pub const DAY: Day<Worksheet, usize, usize> = Day {
+    year: 2025,
+    day: 6,
+    title: "Trash Compactor",
+    display: (
+        "The grand total reading row-wise is: {answer}",
+        "The grand total reading column-wise is: {answer}",
+    ),
+    calc: DayCalc {
+        parse,
+        part1,
+        part2,
+    },
+    variants: Variants::none(),
+    generator: Some(Generator {
+        unit: "problems",
+        write: generate,
+    }),
+    examples: &[Example::both(
+        include_str!("../../../examples/2025/day06.txt"),
+        "4277556",
+        "3263827",
+    )],
+};
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use super::*;
+    use crate::DayTrait as _;
+    use crate::Part;
+    use crate::Printable as _;
+    use crate::answers::expected;
+
+    #[test]
+    fn test_example_part1() {
+        let parse = DAY.calc.parse;
+        let homework = parse(DAY.examples().first()).unwrap();
+        let result = part1(&homework, &(), &Context::none()).unwrap();
+        assert_eq!(result.unwrap(), 4277556);
+    }
+
+    #[test]
+    fn test_example_part2() {
+        let parse = DAY.calc.parse;
+        let homework = parse(DAY.examples().second()).unwrap();
+        let result = part2(&homework, &(), &Context::none()).unwrap();
+        assert_eq!(result.unwrap(), 3263827);
+    }
+
+    #[test]
+    fn test_generated_input_solves() {
+        let worksheet = parse(&generate(200, &mut Rng::new(0))).unwrap();
+        part1(&worksheet, &(), &Context::none()).unwrap();
+        part2(&worksheet, &(), &Context::none()).unwrap();
+    }
+
+    #[test]
+    fn test_main() {
+        let parse = DAY.calc.parse;
+        let homework = parse(&DAY.input().unwrap()).unwrap();
+        assert_eq!(
+            part1(&homework, &(), &Context::none())
+                .unwrap()
+                .answer
+                .to_string(),
+            expected(DAY.year, DAY.day, Part::First)
+        );
+        assert_eq!(
+            part2(&homework, &(), &Context::none())
+                .unwrap()
+                .answer
+                .to_string(),
+            expected(DAY.year, DAY.day, Part::Second)
+        );
+    }
+}
@@ -0,0 +1,329 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    str::FromStr,
+};
+
+use crate::{
+    Context, Day, DayCalc, Example, ParseError, PartOutput, SolveError, SolveResult, Variant,
+    Variants,
+    generate::{Generator, Rng},
+    visualize::Frame,
+};
+
+#[derive(Debug, Clone)]
+pub struct SplitterLocations(Vec<isize>);
+
+#[derive(Debug, Clone)]
+pub struct Diagram {
+    splitter_locations: Vec<SplitterLocations>,
+}
+
+impl FromStr for Diagram {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<Vec<char>> = s.lines().map(|line| line.chars().collect()).collect();
+        let start_idx = lines
+            .first()
+            .ok_or_else(ParseError::empty)?
+            .iter()
+            .position(|c| *c == 'S')
+            .ok_or_else(|| {
+                ParseError::invalid("the first line has no beam start")
+                    .expected("the letter `S`")
+                    .at_line(1, s.lines().next().unwrap_or_default())
+            })?;
+        Ok(Diagram {
+            splitter_locations: lines
+                .into_iter()
+                .skip(1)
+                .filter(|line| line.contains(&'^'))
+                .map(|line| {
+                    SplitterLocations(
+                        line.into_iter()
+                            .enumerate()
+                            .filter(|(_, c)| *c == '^')
+                            .map(|(idx, _)| {
+                                isize::try_from(idx)
+                                    .unwrap()
+                                    .checked_sub(isize::try_from(start_idx).unwrap())
+                                    .unwrap()
+                            })
+                            .collect(),
+                    )
+                })
+                .collect(),
+        })
+    }
+}
+
+impl Display for Diagram {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Splitters, by column relative to the beam start:")?;
+        for splitters in &self.splitter_locations {
+            let columns: Vec<String> = splitters.0.iter().map(isize::to_string).collect();
+            writeln!(f, "  {}", columns.join(" "))?;
+        }
+        Ok(())
+    }
+}
+
+/// The start and the splitter rows that the beams have passed, with `|` wherever a beam leaves a
+/// row.
+///
+/// `beams` holds the beam locations leaving each row so far, starting with the start's.
+fn beam_frame(diagram: &Diagram, beams: &[HashSet<isize>], split_count: usize) -> Frame {
+    let offsets = diagram
+        .splitter_locations
+        .iter()
+        .flat_map(|splitters| splitters.0.iter().copied());
+    let (min, max) = offsets.fold((0, 0), |(min, max), offset| {
+        (min.min(offset - 1), max.max(offset + 1))
+    });
+    let mut rows = vec![
+        (min..=max)
+            .map(|col| if col == 0 { 'S' } else { '.' })
+            .collect(),
+    ];
+    for (splitters, beams) in diagram.splitter_locations.iter().zip(&beams[1..]) {
+        let row = (min..=max)
+            .map(|col| {
+                if splitters.0.contains(&col) {
+                    '^'
+                } else if beams.contains(&col) {
+                    '|'
+                } else {
+                    '.'
+                }
+            })
+            .collect();
+        rows.push(row);
+    }
+    Frame::new(
+        format!("Row {}: {split_count} splits", beams.len() - 1),
+        rows,
+    )
+}
+
+pub fn part1(diagram: &Diagram, _params: &(), ctx: &Context) -> SolveResult<usize> {
+    let mut beam_locations: Vec<HashSet<isize>> = vec![HashSet::from([0])];
+    let mut split_count = 0;
+    for splitter_locations in &diagram.splitter_locations {
+        ctx.check()?;
+        let mut next_beam_locations = HashSet::new();
+        for beam_location in beam_locations.last().unwrap() {
+            if splitter_locations.0.contains(beam_location) {
+                next_beam_locations
+                    .insert(beam_location.checked_sub(1).ok_or(SolveError::Overflow)?);
+                next_beam_locations
+                    .insert(beam_location.checked_add(1).ok_or(SolveError::Overflow)?);
+                split_count += 1;
+            } else {
+                next_beam_locations.insert(*beam_location);
+            }
+        }
+        log::info!("Beam locations: {next_beam_locations:?}");
+        beam_locations.push(next_beam_locations);
+        ctx.frame(|| beam_frame(diagram, &beam_locations, split_count));
+    }
+    Ok(PartOutput {
+        answer: split_count,
+    })
+}
+
+pub fn part2_brute_force(diagram: &Diagram, _params: &(), ctx: &Context) -> SolveResult<usize> {
+    let mut timelines_history: Vec<Vec<isize>> = vec![vec![0]];
+    for splitter_locations in &diagram.splitter_locations {
+        ctx.check()?;
+        let mut next_timeline = Vec::new();
+        for timeline_location in timelines_history.last().unwrap() {
+            if splitter_locations.0.contains(timeline_location) {
+                next_timeline.push(
+                    timeline_location
+                        .checked_sub(1)
+                        .ok_or(SolveError::Overflow)?,
+                );
+                next_timeline.push(
+                    timeline_location
+                        .checked_add(1)
+                        .ok_or(SolveError::Overflow)?,
+                );
+            } else {
+                next_timeline.push(*timeline_location);
+            }
+        }
+        timelines_history.push(next_timeline);
+    }
+    Ok(PartOutput {
+        answer: timelines_history.last().unwrap().len(),
+    })
+}
+
+/// Adds `count` timelines at `location`.
+fn add_timelines(
+    counts: &mut HashMap<isize, usize>,
+    location: isize,
+    count: usize,
+) -> Result<(), SolveError> {
+    let timelines = counts.entry(location).or_insert(0);
+    *timelines = timelines.checked_add(count).ok_or(SolveError::Overflow)?;
+    Ok(())
+}
+
+pub fn part2_counter(diagram: &Diagram, _params: &(), ctx: &Context) -> SolveResult<usize> {
+    let mut timelines_history: Vec<HashMap<isize, usize>> = vec![HashMap::from([(0, 1)])];
+    for splitter_locations in &diagram.splitter_locations {
+        ctx.check()?;
+        let mut next_count = HashMap::new();
+        for (timeline_location, count) in timelines_history.last().unwrap() {
+            if splitter_locations.0.contains(timeline_location) {
+                add_timelines(
+                    &mut next_count,
+                    timeline_location
+                        .checked_sub(1)
+                        .ok_or(SolveError::Overflow)?,
+                    *count,
+                )?;
+                add_timelines(
+                    &mut next_count,
+                    timeline_location
+                        .checked_add(1)
+                        .ok_or(SolveError::Overflow)?,
+                    *count,
+                )?;
+            } else {
+                add_timelines(&mut next_count, *timeline_location, *count)?;
+            }
+        }
+        timelines_history.push(next_count);
+    }
+    Ok(PartOutput {
+        answer: timelines_history
+            .last()
+            .unwrap()
+            .values()
+            .try_fold(0usize, |sum, count| sum.checked_add(*count))
+            .ok_or(SolveError::Overflow)?,
+    })
+}
+
+/// A diagram of `size` rows of splitters, with every splitter within reach of the beam and
+/// blank rows in between, like the real input's.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let width = 2 * size + 1;
+    let blank = format!("{}\n", ".".repeat(width));
+    let mut diagram = blank.clone();
+    diagram.replace_range(size..=size, "S");
+    for row in 0..size {
+        diagram += &blank;
+        let splitters: String = (0..width)
+            .map(|col| {
+                let offset = col.abs_diff(size);
+                let reachable = offset <= row && (row - offset).is_multiple_of(2);
+                if reachable && rng.chance(0.8) {
+                    '^'
+                } else {
+                    '.'
+                }
+            })
+            .collect();
+        diagram += &splitters;
+        diagram.push('\n');
+    }
+    diagram + &blank
+}
+
+pub const DAY: Day<Diagram, usize, usize> = Day {
+    year: 2025,
+    day: 7,
+    title: "Laboratories",
+    display: (
+        "The beam will be split {answer} times.",
+        "A single particle would end up on {answer} different timelines.",
+    ),
+    calc: DayCalc {
+        parse: Diagram::from_str,
+        part1,
+        part2: part2_counter,
+    },
+    variants: Variants {
+        part1: &[],
+        part2: &[Variant {
+            name: "brute_force",
+            solve: part2_brute_force,
+        }],
+    },
+    generator: Some(Generator {
+        unit: "rows of splitters",
+        write: generate,
+    }),
+    examples: &[Example::both(
+        include_str!("../../../examples/2025/day07.txt"),
+        "21",
+        "40",
+    )],
+};
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use super::*;
+    use crate::DayTrait as _;
+    use crate::Part;
+    use crate::Printable as _;
+    use crate::answers::expected;
+
+    #[test]
+    fn test_example_part1() {
+        let parse = DAY.calc.parse;
+        let diagram = parse(DAY.examples().first()).unwrap();
+        let result = part1(&diagram, &(), &Context::none()).unwrap();
+        assert_eq!(result.unwrap(), 21);
+    }
+
+    #[test]
+    fn test_example_part2_brute_force() {
+        let parse = DAY.calc.parse;
+        let diagram = parse(DAY.examples().second()).unwrap();
+        let result = part2_brute_force(&diagram, &(), &Context::none()).unwrap();
+        assert_eq!(result.unwrap(), 40);
+    }
+
+    #[test]
+    fn test_example_part2() {
+        let parse = DAY.calc.parse;
+        let diagram = parse(DAY.examples().second()).unwrap();
+        let result = part2_counter(&diagram, &(), &Context::none()).unwrap();
+        assert_eq!(result.unwrap(), 40);
+    }
+
+    #[test]
+    fn test_generated_input_solves() {
+        let parse = DAY.calc.parse;
+        let diagram = parse(&generate(40, &mut Rng::new(0))).unwrap();
+        part1(&diagram, &(), &Context::none()).unwrap();
+        part2_counter(&diagram, &(), &Context::none()).unwrap();
+    }
+
+    #[test]
+    fn test_main() {
+        let parse = DAY.calc.parse;
+        let diagram = parse(&DAY.input().unwrap()).unwrap();
+        assert_eq!(
+            part1(&diagram, &(), &Context::none())
+                .unwrap()
+                .answer
+                .to_string(),
+            expected(DAY.year, DAY.day, Part::First)
+        );
+        assert_eq!(
+            part2_counter(&diagram, &(), &Context::none())
+                .unwrap()
+                .answer
+                .to_string(),
+            expected(DAY.year, DAY.day, Part::Second)
+        );
+    }
+}
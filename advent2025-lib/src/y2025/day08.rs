@@ -1,6 +1,16 @@
-use std::{collections::HashMap, fmt::Display, num::ParseIntError, str::FromStr};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    num::ParseIntError,
+    str::FromStr,
+};
 
-use crate::{Day, DayCalc, Examples, ParseError, PartOutput};
+use crate::{
+    Context, Day, DayCalc, Example, Params, ParseError, PartOutput, SolveError, SolveResult,
+    Variants,
+    generate::{Generator, Rng},
+    parser,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct JunctionBox([usize; 3]);
@@ -29,18 +39,18 @@ impl JunctionBox {
 }
 
 impl FromStr for JunctionBox {
-    type Err = ParseIntError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let coordinates: Vec<usize> = s
-            .split(',')
-            .map(|pos| pos.parse())
-            .collect::<Result<_, ParseIntError>>()?;
-        Ok(JunctionBox([
-            coordinates[0],
-            coordinates[1],
-            coordinates[2],
-        ]))
+        let coordinates: Vec<usize> = parser::split_columns(s, ',')
+            .map(|(column, pos)| parser::parse_token(pos, column, "a coordinate"))
+            .collect::<Result<_, ParseError>>()?;
+        let coordinates: [usize; 3] = coordinates.try_into().map_err(|coordinates: Vec<_>| {
+            ParseError::invalid(format!("found {} coordinates", coordinates.len()))
+                .at_column(1, s.chars().count())
+                .expected("three comma-separated coordinates")
+        })?;
+        Ok(JunctionBox(coordinates))
     }
 }
 
@@ -70,14 +80,20 @@ impl FromStr for JunctionBoxes {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(JunctionBoxes {
-            boxes: s
-                .lines()
-                .map(|line| line.parse())
-                .collect::<Result<_, _>>()?,
+            boxes: parser::parse_lines(s, JunctionBox::from_str)?,
         })
     }
 }
 
+impl Display for JunctionBoxes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for junction_box in &self.boxes {
+            writeln!(f, "{junction_box}")?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct JunctionBoxTree {
     root: JunctionBox,
@@ -99,32 +115,27 @@ impl JunctionBoxTree {
     }
 }
 
-pub fn distance_matrix(
-    diagram: &JunctionBoxes,
+pub fn distance_matrix<'a>(
+    diagram: &'a JunctionBoxes,
     connection_pairs_max: Option<usize>,
-) -> (Vec<JunctionBoxTree>, Option<[&JunctionBox; 2]>) {
-    let distance_matrix: HashMap<[&JunctionBox; 2], usize> = diagram
-        .boxes
-        .iter()
-        .flat_map(|first| {
-            diagram
-                .boxes
-                .iter()
-                .filter_map(|second| {
-                    if first == second {
-                        None
-                    } else {
-                        let pair = if first.0 < second.0 {
-                            [first, second]
-                        } else {
-                            [second, first]
-                        };
-                        Some((pair, first.idistance(second)))
-                    }
-                })
-                .collect::<Vec<_>>()
-        })
-        .collect();
+    ctx: &Context,
+) -> Result<(Vec<JunctionBoxTree>, Option<[&'a JunctionBox; 2]>), SolveError> {
+    let mut distance_matrix: HashMap<[&JunctionBox; 2], usize> = HashMap::new();
+    for first in &diagram.boxes {
+        ctx.check()?;
+        distance_matrix.extend(diagram.boxes.iter().filter_map(|second| {
+            if first == second {
+                None
+            } else {
+                let pair = if first.0 < second.0 {
+                    [first, second]
+                } else {
+                    [second, first]
+                };
+                Some((pair, first.idistance(second)))
+            }
+        }));
+    }
     let pairs_sorted_by_distance = {
         let mut pairs_sorted_by_distance: Vec<(usize, [&JunctionBox; 2])> = distance_matrix
             .iter()
@@ -139,7 +150,9 @@ pub fn distance_matrix(
     let mut trees: Vec<JunctionBoxTree> = vec![];
     let mut count_connection_pairs = 0;
     let mut last_pair = None;
-    for (_distance, pair) in &pairs_sorted_by_distance {
+    let total = pairs_sorted_by_distance.len() as u64;
+    for (idx, (_distance, pair)) in pairs_sorted_by_distance.iter().enumerate() {
+        ctx.checkpoint(idx as u64, total)?;
         // Both in a tree
         if let (Some(first_in_tree_idx), Some(second_in_tree_idx)) = (
             trees.iter().position(|tree| tree.contains(pair[0])),
@@ -205,52 +218,104 @@ pub fn distance_matrix(
         }
     }
     trees.sort_by_key(|t| usize::MAX - t.len());
-    (trees, last_pair.copied())
+    Ok((trees, last_pair.copied()))
 }
 
 pub fn distance_matrix_tree(
     diagram: &JunctionBoxes,
     connection_pairs_max: usize,
-) -> Vec<JunctionBoxTree> {
-    distance_matrix(diagram, Some(connection_pairs_max)).0
+    ctx: &Context,
+) -> Result<Vec<JunctionBoxTree>, SolveError> {
+    Ok(distance_matrix(diagram, Some(connection_pairs_max), ctx)?.0)
 }
 
-pub fn distance_matrix_last_connection(
-    diagram: &JunctionBoxes,
-) -> (JunctionBoxTree, [&JunctionBox; 2]) {
-    let (trees, pair) = distance_matrix(diagram, None);
-    assert_eq!(trees.len(), 1);
+pub fn distance_matrix_last_connection<'a>(
+    diagram: &'a JunctionBoxes,
+    ctx: &Context,
+) -> Result<(JunctionBoxTree, [&'a JunctionBox; 2]), SolveError> {
+    let (trees, pair) = distance_matrix(diagram, None, ctx)?;
+    if trees.len() != 1 {
+        return Err(SolveError::InvalidState(format!(
+            "expected a single circuit, found {}",
+            trees.len()
+        )));
+    }
     let tree = trees.into_iter().next().unwrap();
     log::info!(
         "Number of boxes: {}, Tree length: {}",
         diagram.boxes.len(),
         tree.len()
     );
-    (tree, pair.unwrap())
+    Ok((tree, pair.ok_or(SolveError::NoSolution)?))
 }
 
-pub fn part1(diagram: &JunctionBoxes) -> PartOutput<usize> {
-    let trees = distance_matrix_tree(diagram, 1000);
-    PartOutput {
-        answer: trees.iter().take(3).map(|t| t.len()).product(),
+/// How many of the closest pairs of junction boxes to connect for part 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Connections {
+    pub pairs: usize,
+}
+
+impl Default for Connections {
+    fn default() -> Self {
+        Connections { pairs: 1000 }
     }
 }
 
-pub fn part2(diagram: &JunctionBoxes) -> PartOutput<usize> {
-    let (tree, last_connection) = distance_matrix_last_connection(diagram);
+impl Params for Connections {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "pairs" => self.pairs = value.parse().map_err(|e: ParseIntError| e.to_string())?,
+            _ => return Err(format!("unknown parameter `{key}`, expected `pairs`")),
+        }
+        Ok(())
+    }
+}
+
+pub fn part1(
+    diagram: &JunctionBoxes,
+    connections: &Connections,
+    ctx: &Context,
+) -> SolveResult<usize> {
+    let trees = distance_matrix_tree(diagram, connections.pairs, ctx)?;
+    Ok(PartOutput {
+        answer: trees.iter().take(3).map(|t| t.len()).product(),
+    })
+}
+
+pub fn part2(
+    diagram: &JunctionBoxes,
+    _connections: &Connections,
+    ctx: &Context,
+) -> SolveResult<usize> {
+    let (tree, last_connection) = distance_matrix_last_connection(diagram, ctx)?;
     log::info!(
         "Number of boxes: {}, Tree length: {}",
         diagram.boxes.len(),
         tree.len()
     );
-    PartOutput {
+    Ok(PartOutput {
         answer: last_connection[0].0[0]
             .checked_mul(last_connection[1].0[0])
-            .unwrap(),
+            .ok_or(SolveError::Overflow)?,
+    })
+}
+
+/// `size` junction boxes at distinct positions of up to 99999 along each axis, like the real
+/// input's.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let mut seen = HashSet::new();
+    let mut boxes = String::new();
+    while seen.len() < size {
+        let position = [0; 3].map(|_| rng.range(0..=99_999));
+        if seen.insert(position) {
+            boxes += &format!("{}\n", JunctionBox(position));
+        }
     }
+    boxes
 }
 
-pub const DAY: Day<JunctionBoxes, usize, 1, 0, 0> = Day {
+pub const DAY: Day<JunctionBoxes, usize, usize, Connections> = Day {
+    year: 2025,
     day: 8,
     title: "Playground  ",
     display: (
@@ -262,7 +327,17 @@ pub const DAY: Day<JunctionBoxes, usize, 1, 0, 0> = Day {
         part1,
         part2,
     },
-    examples: Examples::single(include_str!("../../examples/day08.txt")),
+    variants: Variants::none(),
+    generator: Some(Generator {
+        unit: "junction boxes",
+        write: generate,
+    }),
+    examples: &[Example::both(
+        include_str!("../../../examples/2025/day08.txt"),
+        "40",
+        "25272",
+    )
+    .with_params(&[("pairs", "10")])],
 };
 
 #[cfg(test)]
@@ -271,30 +346,61 @@ mod tests {
 
     use super::*;
     use crate::DayTrait as _;
+    use crate::Part;
     use crate::Printable as _;
+    use crate::answers::expected;
 
     #[test]
     fn test_example_part1() {
         let parse = DAY.calc.parse;
         let diagram = parse(DAY.examples().first()).unwrap();
-        let trees = distance_matrix_tree(&diagram, 10);
-        log::info!("Trees: {:#?}", trees);
-        assert_eq!(trees.iter().take(3).map(|t| t.len()).product::<usize>(), 40);
+        let result = part1(&diagram, &Connections { pairs: 10 }, &Context::none()).unwrap();
+        assert_eq!(result.unwrap(), 40);
+    }
+
+    #[test]
+    fn test_params() {
+        let mut connections = Connections::default();
+        assert_eq!(connections.pairs, 1000);
+        connections.set("pairs", "10").unwrap();
+        assert_eq!(connections, Connections { pairs: 10 });
+        assert!(connections.set("pairs", "ten").is_err());
+        assert!(connections.set("boxes", "10").is_err());
     }
 
     #[test]
     fn test_example_part2() {
         let parse = DAY.calc.parse;
         let diagram = parse(DAY.examples().second()).unwrap();
-        let result = part2(&diagram);
+        let result = part2(&diagram, &Connections::default(), &Context::none()).unwrap();
         assert_eq!(result.unwrap(), 25272);
     }
 
+    #[test]
+    fn test_generated_input_solves() {
+        let parse = DAY.calc.parse;
+        let junction_boxes = parse(&generate(200, &mut Rng::new(0))).unwrap();
+        part1(&junction_boxes, &Connections::default(), &Context::none()).unwrap();
+        part2(&junction_boxes, &Connections::default(), &Context::none()).unwrap();
+    }
+
     #[test]
     fn test_main() {
         let parse = DAY.calc.parse;
-        let diagram = parse(&DAY.input()).unwrap();
-        assert_eq!(part1(&diagram).answer.to_string(), "171503");
-        assert_eq!(part2(&diagram).answer.to_string(), "9069509600");
+        let diagram = parse(&DAY.input().unwrap()).unwrap();
+        assert_eq!(
+            part1(&diagram, &Connections::default(), &Context::none())
+                .unwrap()
+                .answer
+                .to_string(),
+            expected(DAY.year, DAY.day, Part::First)
+        );
+        assert_eq!(
+            part2(&diagram, &Connections::default(), &Context::none())
+                .unwrap()
+                .answer
+                .to_string(),
+            expected(DAY.year, DAY.day, Part::Second)
+        );
     }
 }
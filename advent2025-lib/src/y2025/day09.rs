@@ -0,0 +1,553 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    ops::RangeInclusive,
+    str::FromStr,
+};
+
+use crate::{
+    Context, Day, DayCalc, Example, ParseError, PartOutput, SolveError, SolveResult, Variant,
+    Variants,
+    generate::{Generator, Rng},
+    parser,
+    visualize::Frame,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RowColPos {
+    row: usize,
+    col: usize,
+}
+
+impl RowColPos {
+    fn area(&self, other: &RowColPos) -> Result<usize, SolveError> {
+        (self.row.abs_diff(other.row) + 1)
+            .checked_mul(self.col.abs_diff(other.col) + 1)
+            .ok_or(SolveError::Overflow)
+    }
+    fn neighbours(&self) -> Result<[RowColPos; 4], SolveError> {
+        let overflow = || SolveError::Overflow;
+        Ok([
+            RowColPos {
+                row: self.row,
+                col: self.col.checked_add(1).ok_or_else(overflow)?,
+            },
+            RowColPos {
+                row: self.row,
+                col: self.col.checked_sub(1).ok_or_else(overflow)?,
+            },
+            RowColPos {
+                row: self.row.checked_add(1).ok_or_else(overflow)?,
+                col: self.col,
+            },
+            RowColPos {
+                row: self.row.checked_sub(1).ok_or_else(overflow)?,
+                col: self.col,
+            },
+        ])
+    }
+}
+
+impl FromStr for RowColPos {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (col, row) = s.split_once(',').ok_or_else(|| {
+            ParseError::invalid(format!("invalid position {s:?}"))
+                .at_column(1, s.chars().count())
+                .expected("`,`")
+        })?;
+        Ok(Self {
+            row: parser::parse_token(row, col.chars().count() + 2, "a row")?,
+            col: parser::parse_token(col, 1, "a column")?,
+        })
+    }
+}
+
+impl Display for RowColPos {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.col, self.row)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GridManifest {
+    red_tiles: Vec<RowColPos>,
+}
+
+impl FromStr for GridManifest {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parser::parse_lines(s, RowColPos::from_str).map(|red_tiles| Self { red_tiles })
+    }
+}
+
+impl Display for GridManifest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for tile in &self.red_tiles {
+            writeln!(f, "{tile}")?;
+        }
+        Ok(())
+    }
+}
+
+pub fn part1(grid: &GridManifest, _params: &(), ctx: &Context) -> SolveResult<usize> {
+    log::debug!("Finding the largest area of any rectangle...");
+    let total = (grid.red_tiles.len() as u64).pow(2);
+    Ok(PartOutput {
+        answer: grid
+            .red_tiles
+            .iter()
+            .flat_map(|first| {
+                grid.red_tiles
+                    .iter()
+                    .map(move |second| (first.row <= second.row).then(|| first.area(second)))
+            })
+            .enumerate()
+            .try_fold(0, |largest, (done, area)| {
+                ctx.checkpoint(done as u64, total)?;
+                Ok::<_, SolveError>(area.transpose()?.map_or(largest, |area| largest.max(area)))
+            })?,
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Tile {
+    Red,
+    Green,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Grid(HashMap<RowColPos, Tile>);
+
+impl Grid {
+    fn draw_edge(&mut self, first: &RowColPos, second: &RowColPos) -> Result<(), SolveError> {
+        self.0.insert(*first, Tile::Red);
+        self.0.insert(*second, Tile::Red);
+        if first.row == second.row {
+            for col in first.col.min(second.col) + 1..first.col.max(second.col) {
+                self.draw_green(RowColPos {
+                    row: first.row,
+                    col,
+                })?;
+            }
+        } else if first.col == second.col {
+            for row in first.row.min(second.row) + 1..first.row.max(second.row) {
+                self.draw_green(RowColPos {
+                    row,
+                    col: first.col,
+                })?;
+            }
+        } else {
+            return Err(SolveError::InvalidState(format!(
+                "cannot draw edge between {first:?} and {second:?}"
+            )));
+        }
+        Ok(())
+    }
+    fn draw_green(&mut self, pos: RowColPos) -> Result<(), SolveError> {
+        match self.0.insert(pos, Tile::Green) {
+            None => Ok(()),
+            Some(_) => Err(SolveError::InvalidState(format!("edges cross at {pos:?}"))),
+        }
+    }
+    fn flood_fill_slow(&mut self, midpoint: &RowColPos, ctx: &Context) -> Result<(), SolveError> {
+        log::info!("Flood fill from {:?}", midpoint);
+        let mut tsunami: HashSet<RowColPos> = HashSet::from([midpoint.to_owned()]);
+        for step in 1.. {
+            ctx.check()?;
+            // Green the tsunami
+            for pos in &tsunami {
+                self.0.insert(pos.to_owned(), Tile::Green);
+            }
+            ctx.frame(|| Frame::from_text(format!("Flood fill step {step}"), &self.to_string()));
+            // Extend the tsunami
+            let old_tsunami: Vec<RowColPos> = tsunami.drain().collect();
+            for pos in old_tsunami {
+                for neighbour in pos.neighbours()? {
+                    if !self.0.contains_key(&neighbour) {
+                        tsunami.insert(neighbour);
+                    }
+                }
+            }
+            if tsunami.is_empty() {
+                break;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Display for Grid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut min_row = usize::MAX;
+        let mut max_row = usize::MIN;
+        let mut min_col = usize::MAX;
+        let mut max_col = usize::MIN;
+        for pos in self.0.keys() {
+            min_row = min_row.min(pos.row);
+            max_row = max_row.max(pos.row);
+            min_col = min_col.min(pos.col);
+            max_col = max_col.max(pos.col);
+        }
+        for row in min_row..=max_row {
+            for col in min_col..=max_col {
+                let pos = RowColPos { row, col };
+                let tile_char = self
+                    .0
+                    .get(&pos)
+                    .map(|t| match t {
+                        Tile::Green => 'X',
+                        Tile::Red => '#',
+                    })
+                    .unwrap_or('.');
+                write!(f, "{}", tile_char)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn part2_flood_fill(
+    grid_manifest: &GridManifest,
+    _params: &(),
+    ctx: &Context,
+) -> SolveResult<usize> {
+    let (Some(first_tile), Some(last_tile)) = (
+        grid_manifest.red_tiles.first(),
+        grid_manifest.red_tiles.last(),
+    ) else {
+        return Err(SolveError::NoSolution);
+    };
+    let mut grid = Grid::default();
+    for pair in grid_manifest.red_tiles.windows(2) {
+        log::trace!("Grid:\n{}", grid);
+        grid.draw_edge(&pair[0], &pair[1])?;
+    }
+    grid.draw_edge(last_tile, first_tile)?;
+    log::trace!("Grid:\n{}", grid);
+    ctx.frame(|| Frame::from_text("Edges", &grid.to_string()));
+    let min_col = grid_manifest.red_tiles.iter().map(|t| t.col).min().unwrap();
+    let mid_row = grid_manifest
+        .red_tiles
+        .iter()
+        .map(|t| t.row)
+        .min()
+        .unwrap()
+        .abs_diff(grid_manifest.red_tiles.iter().map(|t| t.row).max().unwrap())
+        / 2;
+    let max_col = grid_manifest.red_tiles.iter().map(|t| t.col).max().unwrap();
+    let mut midpoint = None;
+    // The tile past an edge on the middle row is inside, unless the edge is the rightmost.
+    for col in min_col..max_col {
+        ctx.checkpoint((col - min_col) as u64, (max_col - min_col) as u64)?;
+        log::info!("Looking for midpoint at row {}, col {}", mid_row, col);
+        if grid.0.contains_key(&RowColPos { row: mid_row, col })
+            && !grid.0.contains_key(&RowColPos {
+                row: mid_row,
+                col: col + 1,
+            })
+        {
+            midpoint = Some(RowColPos {
+                row: mid_row,
+                col: col + 1,
+            });
+            break;
+        }
+    }
+    let midpoint = midpoint.ok_or(SolveError::NoSolution)?;
+    grid.flood_fill_slow(&midpoint, ctx)?;
+    log::trace!("Grid:\n{}", grid);
+    let mut possible_areas: Vec<(usize, [RowColPos; 2])> = grid_manifest
+        .red_tiles
+        .iter()
+        .flat_map(|first| {
+            grid_manifest.red_tiles.iter().filter_map(|second| {
+                if first.row <= second.row {
+                    Some(
+                        first
+                            .area(second)
+                            .map(|area| (area, [first.to_owned(), second.to_owned()])),
+                    )
+                } else {
+                    None
+                }
+            })
+        })
+        .collect::<Result<_, _>>()?;
+    possible_areas.sort_unstable_by_key(|area| usize::MAX - area.0);
+    for (area, [first, second]) in possible_areas {
+        ctx.check()?;
+        log::trace!(
+            "Checking rectangle with area {} at {:?}",
+            area,
+            [first, second]
+        );
+        if (first.row.min(second.row)..=first.row.max(second.row)).all(|row| {
+            (first.col.min(second.col)..=first.col.max(second.col)).all(|col| {
+                log::trace!("Checking if grid contains {:?}", RowColPos { row, col });
+                grid.0.contains_key(&RowColPos { row, col })
+            })
+        }) {
+            log::info!(
+                "Found rectangle with area {} at {:?}",
+                area,
+                [first, second]
+            );
+            return Ok(PartOutput { answer: area });
+        }
+    }
+    Err(SolveError::NoSolution)
+}
+
+enum Edge {
+    Horizontal { row: usize, cols: [usize; 2] },
+    Vertical { rows: [usize; 2], col: usize },
+}
+
+pub fn part2(grid_manifest: &GridManifest, _params: &(), ctx: &Context) -> SolveResult<usize> {
+    log::debug!("Finding the largest area of any rectangle using only red and green tiles...");
+    log::debug!("Drawing edges...");
+    ctx.phase("drawing edges");
+    let (Some(first_tile), Some(last_tile)) = (
+        grid_manifest.red_tiles.first(),
+        grid_manifest.red_tiles.last(),
+    ) else {
+        return Err(SolveError::NoSolution);
+    };
+    let edges: Vec<Edge> = {
+        let mut edges = Vec::new();
+        let mut add_pair = |first: &RowColPos, second: &RowColPos| {
+            if first.row == second.row {
+                edges.push(Edge::Horizontal {
+                    row: first.row,
+                    cols: [first.col.min(second.col), first.col.max(second.col)],
+                });
+            } else if first.col == second.col {
+                edges.push(Edge::Vertical {
+                    rows: [first.row.min(second.row), first.row.max(second.row)],
+                    col: first.col,
+                });
+            } else {
+                return Err(SolveError::InvalidState(format!(
+                    "red tiles {first:?} and {second:?} do not share a row or column"
+                )));
+            }
+            Ok(())
+        };
+        for pair in grid_manifest.red_tiles.windows(2) {
+            add_pair(&pair[0], &pair[1])?;
+        }
+        add_pair(last_tile, first_tile)?;
+        edges
+    };
+    log::debug!("Finding possible areas...");
+    ctx.phase("finding possible areas");
+    let mut possible_areas: Vec<(usize, [RowColPos; 2])> = grid_manifest
+        .red_tiles
+        .iter()
+        .flat_map(|first| {
+            grid_manifest.red_tiles.iter().filter_map(|second| {
+                if first.row <= second.row {
+                    Some(
+                        first
+                            .area(second)
+                            .map(|area| (area, [first.to_owned(), second.to_owned()])),
+                    )
+                } else {
+                    None
+                }
+            })
+        })
+        .collect::<Result<_, _>>()?;
+    log::debug!("Sorting possible areas...");
+    ctx.phase("sorting possible areas");
+    possible_areas.sort_unstable_by_key(|area| usize::MAX - area.0);
+    log::debug!("Finding first valid possible areas...");
+    ctx.phase("checking rectangles");
+    let total = possible_areas.len() as u64;
+    for (checked, (area, [rect_1, rect_2])) in possible_areas.into_iter().enumerate() {
+        ctx.checkpoint(checked as u64, total)?;
+        log::trace!(
+            "Checking rectangle with area {} at {:?} to {:?}...",
+            area,
+            rect_1,
+            rect_2,
+        );
+        // Invalid if any edge crosses into this rectangle
+        let rect_top = rect_1.row.min(rect_2.row);
+        let rect_bottom = rect_1.row.max(rect_2.row);
+        let rect_left = rect_1.col.min(rect_2.col);
+        let rect_right = rect_1.col.max(rect_2.col);
+        let invalid_rectangle = edges.iter().any(|edge| match edge {
+            Edge::Horizontal {
+                row,
+                cols: [left, right],
+            } => {
+                (*row > rect_top && *row < rect_bottom)
+                    && ((*left <= rect_left && *right >= rect_left)
+                        || (*left <= rect_right && *right >= rect_right))
+            }
+            Edge::Vertical {
+                col,
+                rows: [top, bottom],
+            } => {
+                (*col > rect_left && *col < rect_right)
+                    && ((*top <= rect_top && *bottom >= rect_top)
+                        || (*top <= rect_bottom && *bottom >= rect_bottom))
+            }
+        });
+        if !invalid_rectangle {
+            log::info!(
+                "Found rectangle with area {} at {:?}",
+                area,
+                [rect_1, rect_2]
+            );
+            return Ok(PartOutput { answer: area });
+        }
+    }
+    Err(SolveError::NoSolution)
+}
+
+/// A rectilinear polygon of about `size` red tiles, rounded down to a multiple of four.
+///
+/// The polygon is a row of columns of random heights, all straddling the middle row so that
+/// neighbouring columns always overlap, which keeps it simple however jagged it gets.
+pub fn generate(size: usize, rng: &mut Rng) -> String {
+    let columns = (size / 4).max(1);
+    let max_step = (100_000 / columns).max(2);
+    let mut cols = vec![rng.range(1..=1000)];
+    for _ in 0..columns {
+        cols.push(cols.last().unwrap() + rng.range(2..=max_step));
+    }
+    // Neighbouring columns differ in height at both ends, so that no corner is redundant.
+    let mut heights = |range: RangeInclusive<usize>| {
+        let mut heights: Vec<usize> = Vec::with_capacity(columns);
+        while heights.len() < columns {
+            let height = rng.range(range.clone());
+            if heights.last() != Some(&height) {
+                heights.push(height);
+            }
+        }
+        heights
+    };
+    let (tops, bottoms) = (heights(1_000..=49_000), heights(51_000..=99_000));
+    let mut tiles = vec![(cols[0], tops[0])];
+    for idx in 1..columns {
+        tiles.extend([(cols[idx], tops[idx - 1]), (cols[idx], tops[idx])]);
+    }
+    tiles.extend([
+        (cols[columns], tops[columns - 1]),
+        (cols[columns], bottoms[columns - 1]),
+    ]);
+    for idx in (1..columns).rev() {
+        tiles.extend([(cols[idx], bottoms[idx]), (cols[idx], bottoms[idx - 1])]);
+    }
+    tiles.push((cols[0], bottoms[0]));
+    tiles
+        .into_iter()
+        .map(|(col, row)| format!("{}\n", RowColPos { row, col }))
+        .collect()
+}
+
+pub const DAY: Day<GridManifest, usize, usize> = Day {
+    year: 2025,
+    day: 9,
+    title: "Movie Theater",
+    display: (
+        "The largest area of any rectangle is: {answer}",
+        "The largest area of any rectangle using only red and green tiles is: {answer}",
+    ),
+    calc: DayCalc {
+        parse: GridManifest::from_str,
+        part1,
+        part2,
+    },
+    variants: Variants {
+        part1: &[],
+        part2: &[Variant {
+            name: "flood_fill",
+            solve: part2_flood_fill,
+        }],
+    },
+    generator: Some(Generator {
+        unit: "red tiles",
+        write: generate,
+    }),
+    examples: &[Example::both(
+        include_str!("../../../examples/2025/day09.txt"),
+        "50",
+        "24",
+    )],
+};
+
+#[cfg(test)]
+mod tests {
+    use test_log::test;
+
+    use super::*;
+    use crate::DayTrait as _;
+    use crate::Part;
+    use crate::Printable as _;
+    use crate::answers::expected;
+
+    #[test]
+    fn test_parse_error_column_counts_chars() {
+        let error = "é7,x".parse::<RowColPos>().unwrap_err();
+        assert_eq!(error.column(), Some(4));
+    }
+
+    #[test]
+    fn test_example_part1() {
+        let parse = DAY.calc.parse;
+        let grid = parse(DAY.examples().first()).unwrap();
+        let result = part1(&grid, &(), &Context::none()).unwrap();
+        assert_eq!(result.unwrap(), 50);
+    }
+
+    #[test]
+    fn test_example_flood_fill() {
+        let parse = DAY.calc.parse;
+        let grid = parse(DAY.examples().second()).unwrap();
+        let result = part2_flood_fill(&grid, &(), &Context::none()).unwrap();
+        assert_eq!(result.unwrap(), 24);
+    }
+
+    #[test]
+    fn test_example_part2() {
+        let parse = DAY.calc.parse;
+        let grid = parse(DAY.examples().second()).unwrap();
+        let result = part2(&grid, &(), &Context::none()).unwrap();
+        assert_eq!(result.unwrap(), 24);
+    }
+
+    #[test]
+    fn test_generated_input_solves() {
+        let parse = DAY.calc.parse;
+        let grid = parse(&generate(500, &mut Rng::new(0))).unwrap();
+        part1(&grid, &(), &Context::none()).unwrap();
+        part2(&grid, &(), &Context::none()).unwrap();
+    }
+
+    #[test]
+    fn test_main() {
+        let parse = DAY.calc.parse;
+        let grid = parse(&DAY.input().unwrap()).unwrap();
+        assert_eq!(
+            part1(&grid, &(), &Context::none())
+                .unwrap()
+                .answer
+                .to_string(),
+            expected(DAY.year, DAY.day, Part::First)
+        );
+        assert_eq!(
+            part2(&grid, &(), &Context::none())
+                .unwrap()
+                .answer
+                .to_string(),
+            expected(DAY.year, DAY.day, Part::Second)
+        );
+    }
+}
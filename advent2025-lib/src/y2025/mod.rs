@@ -0,0 +1,12 @@
+//! The puzzles of 2025, one module per day.
+
+pub mod day00_template;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;